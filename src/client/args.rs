@@ -1,5 +1,10 @@
 use std::collections::HashMap;
+#[cfg(feature = "fs-tokio")]
+use std::path::PathBuf;
+use std::pin::Pin;
 
+use bytes::Bytes;
+use futures::{AsyncRead, Stream};
 use hyper::{
     header::{HeaderName, IntoHeaderName},
     HeaderMap,
@@ -7,13 +12,13 @@ use hyper::{
 
 use crate::{
     datatype::{
-        FromXml, InitiateMultipartUploadResult, ObjectLockConfiguration, RetentionMode, Tagging,
-        ToXml,
+        ChecksumAlgorithm, FromXml, InitiateMultipartUploadResult, ObjectLockConfiguration,
+        RetentionMode, Tagging, ToXml,
     },
-    error::Result,
+    error::{Result, ValueError},
     sse::{Sse, SseCustomerKey},
     time::UtcTime,
-    utils::urlencode,
+    utils::{base64_encode, quote_etag, urlencode, xml_escape},
 };
 
 use super::QueryMap;
@@ -26,6 +31,10 @@ use super::QueryMap;
 /// - `extra_headers`: *Optional*, Extra headers for advanced usage.
 ///
 /// **Note**: Some parameters are only valid in specific methods
+///
+/// The `x-amz-expected-bucket-owner` header is sent by all operations on the bucket and its objects,
+/// including the requests of multipart uploads and batch operations.
+/// Set [CopySource::expected_bucket_owner] for the bucket of a copy source.
 #[derive(Debug, Clone)]
 pub struct BucketArgs {
     pub(crate) name: String,
@@ -72,16 +81,43 @@ where
     }
 }
 
+/// Whether `copy_object` copies the metadata from the source object or replaces it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataDirective {
+    Copy,
+    Replace,
+}
+
+/// Whether `copy_object` copies the tags from the source object or replaces them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaggingDirective {
+    Copy,
+    Replace,
+}
+
+/// Compression applied to the content of an object before uploading.
+#[cfg(feature = "compress")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compress")))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Compression {
+    #[default]
+    None,
+    /// Gzip the content and set `Content-Encoding: gzip`.
+    Gzip,
+}
+
 /// A source object definition for `copy_object` and `upload_part_copy` method.
 #[derive(Debug, Clone)]
 pub struct CopySource {
     bucket_name: String,
     object_name: String,
     region: Option<String>,
+    expected_bucket_owner: Option<String>,
     offset: usize,
     length: usize,
     version_id: Option<String>,
-    metadata_replace: bool,
+    metadata_directive: Option<MetadataDirective>,
+    tagging_directive: Option<TaggingDirective>,
     ssec: Option<HeaderMap>,
     match_etag: Option<String>,
     not_match_etag: Option<String>,
@@ -95,8 +131,10 @@ impl CopySource {
             bucket_name: bucket_name.into(),
             object_name: object_name.into(),
             region: None,
+            expected_bucket_owner: None,
             version_id: None,
-            metadata_replace: false,
+            metadata_directive: None,
+            tagging_directive: None,
             ssec: None,
             match_etag: None,
             not_match_etag: None,
@@ -113,6 +151,13 @@ impl CopySource {
         self
     }
 
+    /// Set the account ID of the expected owner of the source bucket,
+    /// sent as the `x-amz-source-expected-bucket-owner` header.
+    pub fn expected_bucket_owner(mut self, expected_bucket_owner: Option<String>) -> Self {
+        self.expected_bucket_owner = expected_bucket_owner;
+        self
+    }
+
     /// Used only in `upload_part_copy` method.
     ///
     /// **Note**: length must be greater than 0, or both length and offset are 0.
@@ -123,12 +168,32 @@ impl CopySource {
     }
 
     /// When copying an object, preserve all metadata if set `false` (default) or specify new metadata.
+    ///
+    /// Same as [metadata_directive](Self::metadata_directive) with [MetadataDirective::Replace].
     pub fn metadata_replace(mut self, metadata_replace: bool) -> Self {
-        self.metadata_replace = metadata_replace;
+        self.metadata_directive = metadata_replace.then_some(MetadataDirective::Replace);
         self
     }
 
-    /// Set version-ID of the object
+    /// Set the `x-amz-metadata-directive`, S3 uses [MetadataDirective::Copy] if not set.
+    ///
+    /// **Note**: with [MetadataDirective::Replace], the metadata and content type of the destination
+    /// are taken from the destination [KeyArgs], metadata not supplied there is dropped.
+    pub fn metadata_directive(mut self, directive: MetadataDirective) -> Self {
+        self.metadata_directive = Some(directive);
+        self
+    }
+
+    /// Set the `x-amz-tagging-directive`, S3 uses [TaggingDirective::Copy] if not set.
+    ///
+    /// **Note**: with [TaggingDirective::Replace], the tags of the destination are taken from
+    /// the `x-amz-tagging` header set by [KeyArgs::extra_headers], no tags are kept if not supplied.
+    pub fn tagging_directive(mut self, directive: TaggingDirective) -> Self {
+        self.tagging_directive = Some(directive);
+        self
+    }
+
+    /// Set version-ID of the source object, the latest version is copied if not set.
     pub fn version_id<T: Into<String>>(mut self, version_id: T) -> Self {
         self.version_id = Some(version_id.into());
         self
@@ -162,22 +227,75 @@ impl CopySource {
         self
     }
 
+    /// Copy only if the ETag of the source object matches,
+    /// otherwise a `PreconditionFailed` error is returned.
+    pub fn if_match<S: Into<String>>(self, etag: S) -> Self {
+        self.match_etag(Some(quote_etag(etag.into())))
+    }
+
+    /// Copy only if the ETag of the source object does not match,
+    /// otherwise a `PreconditionFailed` error is returned.
+    pub fn if_none_match<S: Into<String>>(self, etag: S) -> Self {
+        self.not_match_etag(Some(quote_etag(etag.into())))
+    }
+
+    /// Copy only if the source object has been modified since the time,
+    /// otherwise a `PreconditionFailed` error is returned.
+    pub fn if_modified_since(self, time: UtcTime) -> Self {
+        self.modified_since(Some(time.http_format_time()))
+    }
+
+    /// Copy only if the source object has not been modified since the time,
+    /// otherwise a `PreconditionFailed` error is returned.
+    pub fn if_unmodified_since(self, time: UtcTime) -> Self {
+        self.unmodified_since(Some(time.http_format_time()))
+    }
+
+    /// Returns the [BucketArgs] and [KeyArgs] of the source object.
+    pub(crate) fn source_args(&self) -> (BucketArgs, KeyArgs) {
+        let bucket = BucketArgs::new(self.bucket_name.as_str())
+            .region(self.region.clone())
+            .expected_bucket_owner(self.expected_bucket_owner.clone());
+        let key = KeyArgs::new(self.object_name.as_str()).version_id(self.version_id.clone());
+        (bucket, key)
+    }
+
     pub(crate) fn args_headers(&self) -> HeaderMap {
         let mut header = HeaderMap::new();
         let mut copy_source =
             urlencode(&format!("/{}/{}", self.bucket_name, self.object_name), true);
         if let Some(version_id) = &self.version_id {
-            copy_source = copy_source + "?versionId=" + version_id;
+            copy_source = copy_source + "?versionId=" + &urlencode(version_id, false);
         }
         header.insert("x-amz-copy-source", copy_source.parse().unwrap());
+        if let Some(owner) = &self.expected_bucket_owner {
+            if let Ok(value) = owner.parse() {
+                header.insert("x-amz-source-expected-bucket-owner", value);
+            }
+        }
         if let Some(value) = &self.match_etag {
             header.insert("x-amz-copy-source-if-match", value.parse().unwrap());
         }
         if let Some(value) = &self.not_match_etag {
             header.insert("x-amz-copy-source-if-none-match", value.parse().unwrap());
         }
-        if self.metadata_replace {
-            header.insert("x-amz-metadata-directive", "REPLACE".parse().unwrap());
+        match self.metadata_directive {
+            Some(MetadataDirective::Copy) => {
+                header.insert("x-amz-metadata-directive", "COPY".parse().unwrap());
+            }
+            Some(MetadataDirective::Replace) => {
+                header.insert("x-amz-metadata-directive", "REPLACE".parse().unwrap());
+            }
+            None => {}
+        }
+        match self.tagging_directive {
+            Some(TaggingDirective::Copy) => {
+                header.insert("x-amz-tagging-directive", "COPY".parse().unwrap());
+            }
+            Some(TaggingDirective::Replace) => {
+                header.insert("x-amz-tagging-directive", "REPLACE".parse().unwrap());
+            }
+            None => {}
         }
         if let Some(value) = &self.modified_since {
             header.insert(
@@ -217,10 +335,18 @@ impl CopySource {
 /// - `version_id`: *Optional*, Version-ID of the object.
 /// - `content_type`: *Optional*, Content type of the object.
 /// - `ssec`: *Optional*, Server-side encryption customer key.
+/// - `sse_kms`: *Optional*, KMS key ID and encryption context of SSE-KMS.
 /// - `offset`: *Optional*, Start byte position of object data.
 /// - `length`: *Optional*, Number of bytes of object data from offset.
+/// - `range_suffix`: *Optional*, Number of bytes of object data at the end of the object.
+/// - `part_number`: *Optional*, Part number of a multipart object to get or stat.
+/// - `checksum_mode`: *Optional*, Return the checksum of the object when getting or stating.
+/// - `verify_etag`: *Optional*, Compare the ETag of an uploaded file with the one computed locally.
+/// - `resilient`: *Optional*, Resume the download by ranged GET if the connection drops.
+/// - `trailing_checksum`: *Optional*, Checksum of the uploaded content computed while sending it.
 /// - `metadata`: *Optional*, user-defined metadata.
 /// - `extra_headers`: *Optional*, Extra headers for advanced usage.
+/// - `extra_querys`: *Optional*, Extra query parameters for advanced usage.
 ///
 /// **Note**: Some parameters are only valid in specific methods
 #[derive(Debug, Clone)]
@@ -229,10 +355,29 @@ pub struct KeyArgs {
     pub(crate) version_id: Option<String>,
     pub(crate) content_type: Option<String>,
     pub(crate) ssec_headers: Option<HeaderMap>,
+    pub(crate) sse_kms_key_id: Option<String>,
+    pub(crate) sse_kms_context: Option<HashMap<String, String>>,
     pub(crate) offset: usize,
     pub(crate) length: usize,
+    pub(crate) range_suffix: Option<usize>,
+    pub(crate) part_number: Option<u32>,
     pub(crate) extra_headers: Option<HeaderMap>,
+    pub(crate) extra_querys: QueryMap,
     pub(crate) metadata: HashMap<String, String>,
+    pub(crate) request_payer: bool,
+    pub(crate) checksum_mode: bool,
+    pub(crate) verify_etag: bool,
+    pub(crate) resilient: bool,
+    pub(crate) trailing_checksum: Option<ChecksumAlgorithm>,
+    pub(crate) if_match: Option<String>,
+    pub(crate) if_none_match: bool,
+    pub(crate) object_lock_mode: Option<RetentionMode>,
+    pub(crate) object_lock_retain_until_date: Option<UtcTime>,
+    pub(crate) object_lock_legal_hold: bool,
+    #[cfg(feature = "compress")]
+    pub(crate) auto_decompress: bool,
+    #[cfg(feature = "compress")]
+    pub(crate) compression: Compression,
 }
 
 impl KeyArgs {
@@ -240,12 +385,31 @@ impl KeyArgs {
         Self {
             name: name.into(),
             extra_headers: None,
+            extra_querys: QueryMap::new(),
             version_id: None,
             content_type: None,
             ssec_headers: None,
+            sse_kms_key_id: None,
+            sse_kms_context: None,
             offset: 0,
             length: 0,
+            range_suffix: None,
+            part_number: None,
             metadata: Default::default(),
+            request_payer: false,
+            checksum_mode: false,
+            verify_etag: false,
+            resilient: false,
+            trailing_checksum: None,
+            if_match: None,
+            if_none_match: false,
+            object_lock_mode: None,
+            object_lock_retain_until_date: None,
+            object_lock_legal_hold: false,
+            #[cfg(feature = "compress")]
+            auto_decompress: false,
+            #[cfg(feature = "compress")]
+            compression: Compression::None,
         }
     }
 
@@ -267,15 +431,189 @@ impl KeyArgs {
         self
     }
 
+    /// Add an extra query parameter for advanced usage, such as server-specific features.
+    ///
+    /// The parameter is merged into the query of the request, such as `get_object` and `put_object`,
+    /// and of the request creating the multipart upload.
+    pub fn extra_query<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.extra_querys.insert(key.into(), value.into());
+        self
+    }
+
     /// Set server-side encryption customer key
     pub fn ssec(mut self, ssec: &SseCustomerKey) -> Self {
         self.ssec_headers = Some(ssec.headers());
         self
     }
 
+    /// Encrypt the uploaded object with SSE-KMS using the KMS key ID.
+    pub fn sse_kms<S: Into<String>>(mut self, key_id: S) -> Self {
+        self.sse_kms_key_id = Some(key_id.into());
+        self
+    }
+
+    /// Set the SSE-KMS encryption context of the uploaded object,
+    /// sent as the base64-encoded JSON `x-amz-server-side-encryption-context` header.
+    ///
+    /// Valid when uploading or copying, the object is encrypted with SSE-KMS
+    /// using the default KMS key if [sse_kms](Self::sse_kms) is not set.
+    pub fn sse_kms_context(mut self, context: HashMap<String, String>) -> Self {
+        self.sse_kms_context = Some(context);
+        self
+    }
+
+    /// Confirms that the requester knows that they will be charged for the request.
+    /// Send `x-amz-request-payer: requester` when accessing objects in a requester-pays bucket.
+    ///
+    /// Default: false
+    pub fn request_payer(mut self, request_payer: bool) -> Self {
+        self.request_payer = request_payer;
+        self
+    }
+
+    /// Send `x-amz-checksum-mode: ENABLED` to get the checksum of the object,
+    /// returned by [ObjectStat::checksum](super::ObjectStat::checksum) if the object was uploaded with one.
+    ///
+    /// Default: false
+    pub fn checksum_mode(mut self, checksum_mode: bool) -> Self {
+        self.checksum_mode = checksum_mode;
+        self
+    }
+
+    /// Compare the ETag returned by uploading a file with the MD5 ETag computed from the file,
+    /// an [Error::ChecksumMismatch](crate::error::Error::ChecksumMismatch) is returned if they differ.
+    ///
+    /// The file is read again after uploading. The check is skipped for SSE-C, SSE-KMS
+    /// and compressed uploads, whose ETag is not the MD5 of the file.
+    ///
+    /// Default: false
+    pub fn verify_etag(mut self, verify_etag: bool) -> Self {
+        self.verify_etag = verify_etag;
+        self
+    }
+
+    /// Resume `fget_object` and `get_object_to_writer` if the connection drops in the middle of the response,
+    /// by a ranged GET from the last received byte with `If-Match` of the ETag of the first response,
+    /// at most [resume_retries](super::MinioBuilder::resume_retries) times.
+    ///
+    /// Ignored with `part_number` or `auto_decompress`, whose content cannot be resumed by range.
+    ///
+    /// Default: false
+    pub fn resilient(mut self, resilient: bool) -> Self {
+        self.resilient = resilient;
+        self
+    }
+
+    /// Compute the checksum of the uploaded content while sending it,
+    /// and send it after the body as the signed trailer `x-amz-checksum-*` of the `aws-chunked` encoding,
+    /// so the object is verified by S3 without buffering the stream.
+    ///
+    /// Streams require [multi_chunked_encoding](super::MinioBuilder::multi_chunked_encoding),
+    /// the length of the stream and no compression, a [ValueError] is returned otherwise.
+    /// The checksum of in-memory bytes is sent as a header.
+    ///
+    /// All algorithms are supported, `SHA1` only if the [CryptoProvider](crate::crypto::CryptoProvider) has SHA-1.
+    pub fn trailing_checksum(mut self, algorithm: ChecksumAlgorithm) -> Self {
+        self.trailing_checksum = Some(algorithm);
+        self
+    }
+
+    /// Whether the ETag of the uploaded object is computed from the MD5 of the content.
+    #[cfg(feature = "fs-tokio")]
+    pub(crate) fn etag_is_md5(&self) -> bool {
+        #[cfg(feature = "compress")]
+        if self.compression != Compression::None {
+            return false;
+        }
+        self.ssec_headers.is_none()
+            && self.sse_kms_key_id.is_none()
+            && self.sse_kms_context.is_none()
+    }
+
+    /// Inflate the content of `get_object` according to the `Content-Encoding` of the response,
+    /// `gzip` and `deflate` are supported.
+    ///
+    /// Default: false, the content is returned as stored.
+    #[cfg(feature = "compress")]
+    pub fn auto_decompress(mut self, auto_decompress: bool) -> Self {
+        self.auto_decompress = auto_decompress;
+        self
+    }
+
+    /// Compress the content before uploading, the compressed content is always
+    /// transmitted through `multipart_upload` if it is not smaller than the part size.
+    ///
+    /// Default: [Compression::None]
+    #[cfg(feature = "compress")]
+    pub fn compress(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the `If-Match` precondition, the operation is performed only if
+    /// the ETag of the object matches, otherwise a `PreconditionFailed` error is returned.
+    ///
+    /// Such as `remove_object`, to avoid deleting an object which has been overwritten.
+    pub fn if_match<S: Into<String>>(mut self, etag: S) -> Self {
+        self.if_match = Some(quote_etag(etag.into()));
+        self
+    }
+
+    /// Set the `If-None-Match: *` precondition of uploading, the object is created only if
+    /// it does not exist, otherwise an [Error::AlreadyExists](crate::error::Error::AlreadyExists) is returned.
+    ///
+    /// Default: false
+    pub fn if_none_match_star(mut self, if_none_match: bool) -> Self {
+        self.if_none_match = if_none_match;
+        self
+    }
+
+    /// Set the Object Lock retention mode of the uploaded object,
+    /// must be set together with [object_lock_retain_until_date](Self::object_lock_retain_until_date).
+    ///
+    /// The bucket must be created with Object Lock enabled.
+    pub fn object_lock_mode(mut self, mode: RetentionMode) -> Self {
+        self.object_lock_mode = Some(mode);
+        self
+    }
+
+    /// Set the date until which the uploaded object is protected by the Object Lock retention,
+    /// must be set together with [object_lock_mode](Self::object_lock_mode).
+    pub fn object_lock_retain_until_date(mut self, date: UtcTime) -> Self {
+        self.object_lock_retain_until_date = Some(date);
+        self
+    }
+
+    /// Place a legal hold on the uploaded object.
+    ///
+    /// Default: false
+    pub fn object_lock_legal_hold(mut self, legal_hold: bool) -> Self {
+        self.object_lock_legal_hold = legal_hold;
+        self
+    }
+
+    /// Whether the uploaded object is protected by Object Lock,
+    /// S3 requires the `Content-MD5` header for such uploads.
+    pub(crate) fn has_object_lock(&self) -> bool {
+        self.object_lock_mode.is_some() || self.object_lock_legal_hold
+    }
+
     /// Returns the range of this [`ObjectArgs`].
-    pub(crate) fn range(&self) -> Option<String> {
-        if self.offset > 0 || self.length > 0 {
+    ///
+    /// Return a [ValueError] if the `range_suffix` is 0 or combined with `offset` or `length`.
+    pub(crate) fn range(&self) -> std::result::Result<Option<String>, ValueError> {
+        if let Some(suffix) = self.range_suffix {
+            if self.offset > 0 || self.length > 0 {
+                return Err(ValueError::from(
+                    "range_suffix cannot be combined with offset or length",
+                ));
+            }
+            if suffix == 0 {
+                return Err(ValueError::from("range_suffix must be positive"));
+            }
+            return Ok(Some(format!("bytes=-{suffix}")));
+        }
+        Ok(if self.offset > 0 || self.length > 0 {
             Some(if self.length > 0 {
                 format!("bytes={}-{}", self.offset, self.offset + self.length - 1)
             } else {
@@ -283,7 +621,7 @@ impl KeyArgs {
             })
         } else {
             None
-        }
+        })
     }
 
     /// Set start byte position of object data when `download` an object.
@@ -304,6 +642,25 @@ impl KeyArgs {
         self
     }
 
+    /// Set number of bytes of object data at the end of the object when `download` an object,
+    /// sent as the suffix range `bytes=-{range_suffix}`, e.g. to read the tail of a log
+    /// without knowing its size. The whole object is returned if it is smaller.
+    ///
+    /// **Note**: it cannot be combined with [offset](Self::offset) or [length](Self::length).
+    pub fn range_suffix(mut self, range_suffix: usize) -> Self {
+        self.range_suffix = Some(range_suffix);
+        self
+    }
+
+    /// Set the part number of a multipart object, `get_object` returns the content of the part,
+    /// and `stat_object` the size of the part and the number of parts of the object.
+    ///
+    /// Part numbers start from 1, the whole object is one part if it was not uploaded in parts.
+    pub fn part_number(mut self, part_number: u32) -> Self {
+        self.part_number = Some(part_number);
+        self
+    }
+
     /// Set user-defined metadata when `uploading` an object.
     /// Metadata is a set of key-value pairs.
     ///
@@ -311,20 +668,69 @@ impl KeyArgs {
     /// - requirement is ASCII and cannot contain non-ASCII characters
     /// - Cannot contain invisible characters and spaces
     /// - does't need to start with `x-amz-meta-`
-    /// - ignoring case
+    /// - ignoring case, sent in lowercase
     ///
+    /// Each entry is sent as a `x-amz-meta-<key>` header when uploading or copying,
+    /// and is read back without the prefix by `stat_object`.
     pub fn metadata(mut self, metadata: HashMap<String, String>) -> Self {
         self.metadata = metadata;
         self
     }
 
-    /// Returns the metadata header of this [`ObjectArgs`].
+    /// Returns the metadata, SSE-KMS and Object Lock header of this [`ObjectArgs`].
     pub(crate) fn get_metadata_header(&self) -> Result<HeaderMap> {
         let mut meta_header: HeaderMap = HeaderMap::new();
         for (key, value) in &self.metadata {
+            let key = key.to_ascii_lowercase();
+            let key = key.strip_prefix("x-amz-meta-").unwrap_or(&key);
+            if key.is_empty() || !key.bytes().all(|b| b.is_ascii_graphic()) {
+                return Err(ValueError::new(format!("invalid metadata key: {key:?}")).into());
+            }
             let key = HeaderName::from_bytes(format!("x-amz-meta-{}", key).as_bytes())?;
             meta_header.insert(key, value.parse()?);
         }
+        match (&self.object_lock_mode, &self.object_lock_retain_until_date) {
+            (Some(mode), Some(date)) => {
+                let mode = match mode {
+                    RetentionMode::GOVERNANCE => "GOVERNANCE",
+                    RetentionMode::COMPLIANCE => "COMPLIANCE",
+                };
+                meta_header.insert("x-amz-object-lock-mode", mode.parse()?);
+                meta_header.insert(
+                    "x-amz-object-lock-retain-until-date",
+                    date.format_time().parse()?,
+                );
+            }
+            (None, None) => {}
+            _ => {
+                return Err(ValueError::from(
+                    "object lock mode and retain until date must be set together",
+                )
+                .into())
+            }
+        }
+        if self.object_lock_legal_hold {
+            meta_header.insert("x-amz-object-lock-legal-hold", "ON".parse()?);
+        }
+        if self.sse_kms_key_id.is_some() || self.sse_kms_context.is_some() {
+            meta_header.insert("x-amz-server-side-encryption", "aws:kms".parse()?);
+        }
+        if let Some(key_id) = &self.sse_kms_key_id {
+            meta_header.insert(
+                "x-amz-server-side-encryption-aws-kms-key-id",
+                key_id.parse()?,
+            );
+        }
+        if let Some(context) = &self.sse_kms_context {
+            // sorted, so that the header is the same for the same context
+            let context: std::collections::BTreeMap<_, _> = context.iter().collect();
+            let json = serde_json::to_string(&context)
+                .map_err(|e| ValueError::new(format!("invalid encryption context: {e}")))?;
+            meta_header.insert(
+                "x-amz-server-side-encryption-context",
+                base64_encode(json).parse()?,
+            );
+        }
         Ok(meta_header)
     }
 }
@@ -607,6 +1013,29 @@ impl ListObjectsArgs {
     }
 }
 
+/// Source of the data to `upload` an object.
+///
+/// The optional `usize` of [Reader](UploadSource::Reader) and [Stream](UploadSource::Stream)
+/// is the total byte length of the data, if set None, the data will be transmitted through `multipart_upload`.
+pub enum UploadSource {
+    Bytes(Bytes),
+    /// Path of a local file.
+    #[cfg(feature = "fs-tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fs-tokio")))]
+    File(PathBuf),
+    Reader(Pin<Box<dyn AsyncRead + Sync + Send>>, Option<usize>),
+    Stream(
+        Pin<Box<dyn Stream<Item = Result<Bytes>> + Sync + Send>>,
+        Option<usize>,
+    ),
+}
+
+impl From<Bytes> for UploadSource {
+    fn from(value: Bytes) -> Self {
+        Self::Bytes(value)
+    }
+}
+
 /// Custom request parameters for multiUpload operations.
 ///
 /// Used in `abort_multipart_upload`, `complete_multipart_upload`, `create_multipart_upload`,
@@ -781,13 +1210,93 @@ impl FromXml for ObjectLockConfig {
     }
 }
 
+/// Conditions of a POST policy, which lets browsers upload objects through HTML forms.
+///
+/// The conditions set with `eq` are also added to the form fields returned by `presigned_post_policy`.
+/// ## Example
+/// ```rust
+/// use sc_minio::client::PostPolicy;
+///
+/// let policy = PostPolicy::new()
+///     .expires(600)
+///     .key_starts_with("uploads/")
+///     .content_type_starts_with("image/")
+///     .content_length_range(1, 10 * 1024 * 1024);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PostPolicy {
+    pub(crate) expires: usize,
+    pub(crate) conditions: Vec<(&'static str, String, String)>,
+    pub(crate) content_length_range: Option<(usize, usize)>,
+}
+
+impl Default for PostPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PostPolicy {
+    pub fn new() -> Self {
+        Self {
+            expires: 604800,
+            conditions: vec![],
+            content_length_range: None,
+        }
+    }
+
+    /// Set the expiry in seconds, between 1 second to 7 days. Default: 7 days.
+    pub fn expires(mut self, expires: usize) -> Self {
+        self.expires = expires;
+        self
+    }
+
+    /// The form field must be equal to the value.
+    pub fn eq<K: Into<String>, V: Into<String>>(mut self, field: K, value: V) -> Self {
+        self.conditions.push(("eq", field.into(), value.into()));
+        self
+    }
+
+    /// The form field must start with the value.
+    pub fn starts_with<K: Into<String>, V: Into<String>>(mut self, field: K, value: V) -> Self {
+        self.conditions
+            .push(("starts-with", field.into(), value.into()));
+        self
+    }
+
+    /// The object name must be the key.
+    pub fn key<K: Into<String>>(self, key: K) -> Self {
+        self.eq("key", key)
+    }
+
+    /// The object name must start with the prefix,
+    /// the `key` form field is to be filled in by the browser.
+    pub fn key_starts_with<P: Into<String>>(self, prefix: P) -> Self {
+        self.starts_with("key", prefix)
+    }
+
+    pub fn content_type<T: Into<String>>(self, content_type: T) -> Self {
+        self.eq("Content-Type", content_type)
+    }
+
+    pub fn content_type_starts_with<T: Into<String>>(self, prefix: T) -> Self {
+        self.starts_with("Content-Type", prefix)
+    }
+
+    /// The size of the uploaded content must be between the min and max bytes.
+    pub fn content_length_range(mut self, min: usize, max: usize) -> Self {
+        self.content_length_range = Some((min, max));
+        self
+    }
+}
+
 /// Custom request parameters for presigned URL
 /// ## param
 /// - bucket_name: Name of the bucket.
 /// - object_name: Object name in the bucket.
 /// - expires: Expiry in seconds; defaults to 7 days.
 /// - headers: Optional response_headers argument to specify response fields like date, size, type of file, data about server, etc.
-/// - request_date: Optional request_date argument to specify a different request date. Default is current date.
+/// - signing_time: Optional time the URL is signed at, the URL is valid from it until `expires` seconds later. Default is current time.
 /// - version_id: Version ID of the object.
 /// - querys: Extra query parameters for advanced usage.
 #[derive(Clone)]
@@ -826,11 +1335,22 @@ impl PresignedArgs {
         self
     }
 
+    #[deprecated(note = "use `signing_time` instead")]
     pub fn regirequest_date(mut self, request_date: UtcTime) -> Self {
         self.request_date = Some(request_date);
         self
     }
 
+    /// Set the `X-Amz-Date` of the URL, in the past or future relative to now.
+    ///
+    /// S3 rejects the URL before the signing time and after `expires` seconds from it,
+    /// so a future signing time delays the start of the validity window,
+    /// e.g. for URLs generated in batch ahead of use.
+    pub fn signing_time<T: Into<UtcTime>>(mut self, signing_time: T) -> Self {
+        self.request_date = Some(signing_time.into());
+        self
+    }
+
     pub fn expires(mut self, expires: usize) -> Self {
         self.expires = expires;
         self
@@ -841,13 +1361,21 @@ impl PresignedArgs {
         self
     }
 
+    /// Insert a response header, an invalid value is ignored,
+    /// with a warning if the `tracing` feature is enabled.
     pub fn header<K>(mut self, key: K, value: &str) -> Self
     where
         K: IntoHeaderName,
     {
         let mut headers = self.headers.unwrap_or(HeaderMap::new());
-        if let Ok(value) = value.parse() {
-            headers.insert(key, value);
+        match value.parse() {
+            Ok(value) => {
+                headers.insert(key, value);
+            }
+            Err(_) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(value, "invalid header value of presigned URL is ignored");
+            }
         }
         self.headers = Some(headers);
         self
@@ -888,8 +1416,8 @@ impl Tags {
     }
 
     pub fn to_query(&self) -> String {
-        self.0
-            .iter()
+        self.sorted()
+            .into_iter()
             .map(|(key, value)| format!("{}={}", urlencode(key, false), urlencode(value, false)))
             .collect::<Vec<String>>()
             .join("&")
@@ -903,6 +1431,39 @@ impl Tags {
     pub fn into_map(self) -> HashMap<String, String> {
         self.0
     }
+
+    /// Return the tags ordered by key, so the encoded query and XML are stable.
+    fn sorted(&self) -> Vec<(&String, &String)> {
+        let mut tags: Vec<_> = self.0.iter().collect();
+        tags.sort();
+        tags
+    }
+
+    /// Check the tags against the limits of S3:
+    /// at most `max_count` tags, keys of 1 to 128 characters and values of at most 256 characters.
+    pub(crate) fn validate(&self, max_count: usize) -> std::result::Result<(), ValueError> {
+        if self.0.len() > max_count {
+            return Err(ValueError::new(format!(
+                "the number of tags must not exceed {max_count}, got {}.",
+                self.0.len()
+            )));
+        }
+        for (key, value) in &self.0 {
+            let key_len = key.chars().count();
+            if key_len == 0 || key_len > 128 {
+                return Err(ValueError::new(format!(
+                    "tag key must be 1 to 128 characters long, got {key_len}."
+                )));
+            }
+            let value_len = value.chars().count();
+            if value_len > 256 {
+                return Err(ValueError::new(format!(
+                    "tag value of key '{key}' must be at most 256 characters long, got {value_len}."
+                )));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl From<HashMap<String, String>> for Tags {
@@ -946,10 +1507,196 @@ impl FromXml for Tags {
 impl ToXml for Tags {
     fn to_xml(&self) -> crate::error::Result<String> {
         let mut result = "<Tagging><TagSet>".to_string();
-        for (key, value) in &self.0 {
-            result += &format!("<Tag><Key>{}</Key><Value>{}</Value></Tag>", key, value);
+        for (key, value) in self.sorted() {
+            result += &format!(
+                "<Tag><Key>{}</Key><Value>{}</Value></Tag>",
+                xml_escape(key),
+                xml_escape(value)
+            );
         }
         result += "</TagSet></Tagging>";
         return Ok(result);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{CopySource, KeyArgs, MetadataDirective, TaggingDirective, Tags};
+    use crate::datatype::{FromXml, ToXml};
+
+    #[test]
+    fn test_copy_source_directive() {
+        let headers = CopySource::new("bucket", "key").args_headers();
+        assert!(!headers.contains_key("x-amz-metadata-directive"));
+        assert!(!headers.contains_key("x-amz-tagging-directive"));
+
+        let headers = CopySource::new("bucket", "key")
+            .metadata_directive(MetadataDirective::Copy)
+            .tagging_directive(TaggingDirective::Replace)
+            .args_headers();
+        assert_eq!(headers["x-amz-metadata-directive"], "COPY");
+        assert_eq!(headers["x-amz-tagging-directive"], "REPLACE");
+
+        let headers = CopySource::new("bucket", "key")
+            .metadata_replace(true)
+            .args_headers();
+        assert_eq!(headers["x-amz-metadata-directive"], "REPLACE");
+    }
+
+    #[test]
+    fn test_copy_source_expected_bucket_owner() {
+        let headers = CopySource::new("bucket", "key").args_headers();
+        assert!(!headers.contains_key("x-amz-source-expected-bucket-owner"));
+
+        let src =
+            CopySource::new("bucket", "key").expected_bucket_owner(Some("111122223333".into()));
+        assert_eq!(
+            src.args_headers()["x-amz-source-expected-bucket-owner"],
+            "111122223333"
+        );
+        let (bucket, _) = src.source_args();
+        assert_eq!(
+            bucket.expected_bucket_owner.as_deref(),
+            Some("111122223333")
+        );
+    }
+
+    #[test]
+    fn test_tags_validate() {
+        let mut tags = Tags::new();
+        for i in 0..10 {
+            tags.insert(format!("key{i}"), "value");
+        }
+        assert!(tags.validate(10).is_ok());
+        tags.insert("key10", "value");
+        assert!(tags.validate(10).is_err());
+        assert!(tags.validate(50).is_ok());
+
+        let mut tags = Tags::new();
+        tags.insert("key", "v".repeat(256));
+        assert!(tags.validate(10).is_ok());
+        tags.insert("key", "v".repeat(257));
+        assert!(tags.validate(10).is_err());
+
+        let mut tags = Tags::new();
+        tags.insert("k".repeat(129), "value");
+        assert!(tags.validate(10).is_err());
+        let mut tags = Tags::new();
+        tags.insert("", "value");
+        assert!(tags.validate(10).is_err());
+    }
+
+    #[test]
+    fn test_metadata_header() {
+        let metadata: HashMap<String, String> = [
+            ("Filename".to_owned(), "file.txt".to_owned()),
+            ("x-amz-meta-owner".to_owned(), "minio".to_owned()),
+            ("size".to_owned(), "11".to_owned()),
+        ]
+        .into();
+        let headers = KeyArgs::new("file.txt")
+            .metadata(metadata)
+            .get_metadata_header()
+            .unwrap();
+        assert_eq!(headers.len(), 3);
+        assert_eq!(headers["x-amz-meta-filename"], "file.txt");
+        assert_eq!(headers["x-amz-meta-owner"], "minio");
+        assert_eq!(headers["x-amz-meta-size"], "11");
+
+        for key in ["", "file name", "x-amz-meta-", "名前"] {
+            let metadata = [(key.to_owned(), "value".to_owned())].into();
+            let args = KeyArgs::new("file.txt").metadata(metadata);
+            assert!(args.get_metadata_header().is_err());
+        }
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(KeyArgs::new("key").range().unwrap(), None);
+        let key = KeyArgs::new("key").offset(10).length(5);
+        assert_eq!(key.range().unwrap().unwrap(), "bytes=10-14");
+        let key = KeyArgs::new("key").offset(10);
+        assert_eq!(key.range().unwrap().unwrap(), "bytes=10-");
+        let key = KeyArgs::new("key").range_suffix(100);
+        assert_eq!(key.range().unwrap().unwrap(), "bytes=-100");
+
+        assert!(KeyArgs::new("key").range_suffix(0).range().is_err());
+        assert!(KeyArgs::new("key")
+            .offset(10)
+            .range_suffix(100)
+            .range()
+            .is_err());
+        assert!(KeyArgs::new("key")
+            .length(10)
+            .range_suffix(100)
+            .range()
+            .is_err());
+    }
+
+    #[test]
+    fn test_sse_kms_header() {
+        use base64::Engine;
+
+        let context = [
+            ("project".to_owned(), "minio".to_owned()),
+            ("env".to_owned(), "\"prod\"".to_owned()),
+        ]
+        .into();
+        let headers = KeyArgs::new("file.txt")
+            .sse_kms("key-1")
+            .sse_kms_context(context)
+            .get_metadata_header()
+            .unwrap();
+        assert_eq!(headers["x-amz-server-side-encryption"], "aws:kms");
+        assert_eq!(
+            headers["x-amz-server-side-encryption-aws-kms-key-id"],
+            "key-1"
+        );
+        let context = headers["x-amz-server-side-encryption-context"]
+            .to_str()
+            .unwrap();
+        let context = base64::engine::general_purpose::STANDARD
+            .decode(context)
+            .unwrap();
+        assert_eq!(context, br#"{"env":"\"prod\"","project":"minio"}"#);
+        assert!(KeyArgs::new("file.txt")
+            .get_metadata_header()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_tags_to_xml() {
+        let mut tags = Tags::new();
+        tags.insert("a&b", "<c>");
+        assert_eq!(
+            tags.to_xml().unwrap(),
+            "<Tagging><TagSet><Tag><Key>a&amp;b</Key><Value>&lt;c&gt;</Value></Tag></TagSet></Tagging>"
+        );
+    }
+
+    #[test]
+    fn test_tags_from_xml() {
+        let mut tags = Tags::new();
+        for i in 0..10 {
+            tags.insert(format!("key{i}"), format!("value{i}"));
+        }
+        let xml = tags.to_xml().unwrap();
+        assert!(xml.starts_with(
+            "<Tagging><TagSet><Tag><Key>key0</Key><Value>value0</Value></Tag><Tag><Key>key1</Key>"
+        ));
+        let parsed = Tags::from_xml(xml).unwrap();
+        assert_eq!(parsed.len(), 10);
+        assert_eq!(parsed.into_map(), tags.into_map());
+
+        for xml in [
+            "<Tagging><TagSet></TagSet></Tagging>",
+            "<Tagging><TagSet/></Tagging>",
+            "<Tagging></Tagging>",
+        ] {
+            assert!(Tags::from_xml(xml.to_string()).unwrap().is_empty());
+        }
+    }
+}
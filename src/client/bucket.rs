@@ -1,5 +1,7 @@
+use std::collections::HashMap;
 use std::path::Path;
 use std::pin::Pin;
+use std::time::Duration;
 
 use bytes::Bytes;
 use futures_core::Stream;
@@ -7,12 +9,17 @@ use hyper::Method;
 use reqwest::Response;
 
 use super::{BucketArgs, CopySource, KeyArgs, ListObjectsArgs, ObjectLockConfig, Tags};
-use super::{ObjectStat, SelectObjectReader};
+use super::{
+    GetObjectResponse, ObjectDescription, ObjectStat, PutObjectResult, RemoveObjectResult,
+    SelectObjectReader, UploadSource,
+};
+use crate::datatype::WebsiteConfiguration;
 use crate::datatype::{
-    AccessControlPolicy, CORSConfiguration, ListBucketResult, PublicAccessBlockConfiguration,
-    Retention,
+    AccelerateConfiguration, AccessControlPolicy, BucketLoggingStatus, BucketPolicy,
+    CORSConfiguration, LegalHold, ListBucketResult, OwnershipControls,
+    PublicAccessBlockConfiguration, RequestPaymentConfiguration, Retention,
 };
-use crate::datatype::{SelectRequest, ServerSideEncryptionConfiguration};
+use crate::datatype::{SelectRequest, ServerSideEncryptionConfiguration, StorageClass};
 use crate::{error::Result, Minio};
 
 /// Instantiate an Bucket which wrap [Minio] and [BucketArgs].
@@ -89,6 +96,7 @@ impl Bucket {
     proxy_bucket!(list_objects, ListBucketResult, ListObjectsArgs);
     proxy_bucket!(get_bucket_acl=>get_acl, AccessControlPolicy);
     proxy_bucket!(get_bucket_region=>get_region, String);
+    proxy_bucket!(head_bucket=>head, Option<String>);
 
     proxy_bucket!(get_bucket_cors=>get_cors, CORSConfiguration);
     proxy_bucket!(set_bucket_cors=>set_cors, (),CORSConfiguration);
@@ -102,6 +110,30 @@ impl Bucket {
     proxy_bucket!(set_public_access_block, (), PublicAccessBlockConfiguration);
     proxy_bucket!(del_public_access_block, ());
 
+    proxy_bucket!(get_bucket_ownership_controls=>get_ownership_controls, OwnershipControls);
+    proxy_bucket!(set_bucket_ownership_controls=>set_ownership_controls, (), OwnershipControls);
+
+    #[rustfmt::skip]
+    proxy_bucket!(get_bucket_accelerate_configuration=>get_accelerate_configuration, AccelerateConfiguration);
+    #[rustfmt::skip]
+    proxy_bucket!(set_bucket_accelerate_configuration=>set_accelerate_configuration, (), AccelerateConfiguration);
+
+    #[rustfmt::skip]
+    proxy_bucket!(get_bucket_request_payment=>get_request_payment, RequestPaymentConfiguration);
+    #[rustfmt::skip]
+    proxy_bucket!(set_bucket_request_payment=>set_request_payment, (), RequestPaymentConfiguration);
+
+    proxy_bucket!(get_bucket_logging=>get_logging, BucketLoggingStatus);
+    proxy_bucket!(set_bucket_logging=>set_logging, (), BucketLoggingStatus);
+
+    proxy_bucket!(get_bucket_website=>get_website, Option<WebsiteConfiguration>);
+    proxy_bucket!(set_bucket_website=>set_website, (), WebsiteConfiguration);
+    proxy_bucket!(del_bucket_website=>del_website, ());
+
+    proxy_bucket!(get_bucket_policy=>get_policy, Option<BucketPolicy>);
+    proxy_bucket!(set_bucket_policy=>set_policy, (), BucketPolicy);
+    proxy_bucket!(del_bucket_policy=>del_policy, ());
+
     proxy_bucket!(get_bucket_tags=>get_tags, Option<Tags>);
     proxy_bucket!(set_bucket_tags=>set_tags, (),Tags);
     proxy_bucket!(del_bucket_tags=>del_tags,());
@@ -110,13 +142,25 @@ impl Bucket {
     proxy_bucket!(get_object_lock_config, ObjectLockConfig);
     proxy_bucket!(set_object_lock_config, (), ObjectLockConfig);
 
+    proxy_bucket!(abort_incomplete_multipart_uploads, usize, Option<Duration>);
+
     proxy_object!(get_object, Response);
+    proxy_object!(get_object_response, GetObjectResponse);
+    proxy_object!(get_object_string, String);
     proxy_object!(get_object_torrent, Response);
     proxy_object!(put_object, (), data=>Bytes);
+    proxy_object!(put_object_if_not_exists, (), data=>Bytes);
     proxy_object!(put_object_stream, (), stream=>FsStream, len=>Option<usize>);
+    proxy_object!(upload, PutObjectResult, source=>UploadSource);
     proxy_object!(copy_object, (), cp=> CopySource);
-    proxy_object!(remove_object, ());
+    proxy_object!(move_object, (), cp=> CopySource);
+    proxy_object!(update_object_metadata, (), metadata=>HashMap<String, String>);
+    proxy_object!(set_object_storage_class, (), class=>StorageClass);
+    proxy_object!(remove_object, RemoveObjectResult);
+    proxy_object!(head_object, Option<Response>);
     proxy_object!(stat_object, Option<ObjectStat>);
+    proxy_object!(describe_object, Option<ObjectDescription>);
+    proxy_object!(get_object_legal_hold, LegalHold);
     proxy_object!(is_object_legal_hold_enabled, bool);
     proxy_object!(enable_object_legal_hold_enabled, ());
     proxy_object!(disable_object_legal_hold_enabled, ());
@@ -128,6 +172,13 @@ impl Bucket {
     proxy_object!(select_object_content, SelectObjectReader, request=>SelectRequest);
     proxy_object!(get_object_acl, AccessControlPolicy);
 
+    #[inline]
+    pub async fn create_object_prefix(&self, prefix: &str) -> Result<()> {
+        self.client
+            .create_object_prefix(self.bucket.clone(), prefix)
+            .await
+    }
+
     /// Construct an object url from a key (filename)
     pub fn object_url<K>(&self, key: K) -> String
     where
@@ -149,6 +200,56 @@ impl Bucket {
             .await
     }
 
+    #[cfg(feature = "fs-tokio")]
+    #[inline]
+    pub async fn fget_object_concurrent<K, P>(
+        &self,
+        key: K,
+        path: P,
+        concurrency: usize,
+        part_size: usize,
+    ) -> Result<()>
+    where
+        K: Into<KeyArgs>,
+        P: AsRef<Path>,
+    {
+        self.client
+            .fget_object_concurrent(self.bucket.clone(), key, path, concurrency, part_size)
+            .await
+    }
+
+    #[cfg(feature = "fs-tokio")]
+    #[inline]
+    pub async fn fget_object_resumable<K, P>(&self, key: K, path: P) -> Result<()>
+    where
+        K: Into<KeyArgs>,
+        P: AsRef<Path>,
+    {
+        self.client
+            .fget_object_resumable(self.bucket.clone(), key, path)
+            .await
+    }
+
+    #[inline]
+    pub async fn put_object_json<K, T>(&self, key: K, value: &T) -> Result<()>
+    where
+        K: Into<KeyArgs>,
+        T: serde::Serialize + ?Sized,
+    {
+        self.client
+            .put_object_json(self.bucket.clone(), key, value)
+            .await
+    }
+
+    #[inline]
+    pub async fn get_object_json<K, T>(&self, key: K) -> Result<T>
+    where
+        K: Into<KeyArgs>,
+        T: serde::de::DeserializeOwned,
+    {
+        self.client.get_object_json(self.bucket.clone(), key).await
+    }
+
     #[cfg(feature = "fs-tokio")]
     #[inline]
     pub async fn fput_object<K, P>(&self, key: K, path: P) -> Result<()>
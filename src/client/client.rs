@@ -1,17 +1,24 @@
+use std::collections::HashMap;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
 use crate::data::Data;
-use crate::error::{Error, Result, ValueError};
+use crate::error::{Error, Result, S3Error, ValueError};
 use crate::provider::Provider;
-use crate::signer::sign_request_v4;
-use crate::utils::{check_bucket_name, urlencode, _VALID_ENDPOINT};
+use crate::signer::{_sign_request_v2, _sign_request_v4, SignatureVersion};
+use crate::signer::{MAX_MULTIPART_OBJECT_SIZE, MAX_PART_SIZE, MIN_PART_SIZE};
+use crate::time::UtcTime;
+#[cfg(feature = "mock")]
+use crate::transport::Transport;
+use crate::utils::{check_bucket_name, check_object_name, urlencode, _VALID_ENDPOINT};
 use crate::Credentials;
+use bytes::BytesMut;
 use hyper::{header, header::HeaderValue, HeaderMap};
 use hyper::{Method, Uri};
 use reqwest::{Body, Response};
 
-use super::{Bucket, BucketArgs};
+use super::{Bucket, BucketArgs, BuiltRequest};
 
 /// A `MinioBuilder` can be used to create a [`Minio`] with custom configuration.
 pub struct MinioBuilder {
@@ -23,9 +30,106 @@ pub struct MinioBuilder {
     agent: String,
     secure: bool,
     virtual_hosted: bool,
+    path_prefix: String,
     multi_chunked_encoding: bool,
+    unsigned_payload: bool,
+    part_size: usize,
+    min_part_size: usize,
+    max_inflight_parts: usize,
+    max_object_size: usize,
+    max_error_body_bytes: usize,
+    resume_retries: usize,
     provider: Option<Box<dyn Provider>>,
     client: Option<reqwest::Client>,
+    pool_max_idle_per_host: usize,
+    pool_idle_timeout: Option<Duration>,
+    http2_prior_knowledge: bool,
+    proxies: Vec<reqwest::Proxy>,
+    danger_accept_invalid_certs: bool,
+    root_certificates: Vec<reqwest::Certificate>,
+    check_object_name: bool,
+    signature_version: SignatureVersion,
+    clock: Option<Box<Clock>>,
+    default_bucket: Option<String>,
+    #[cfg(feature = "mock")]
+    transport: Option<Box<dyn Transport>>,
+}
+
+type Clock = dyn Fn() -> UtcTime + Send + Sync;
+
+/// The serializable configuration of a [MinioBuilder], to persist and reload the client configuration.
+///
+/// The credential provider, custom [reqwest::Client], proxies, root certificates and clock are not included,
+/// they are set on the builder returned by [MinioBuilder::from_config].
+/// Missing fields take the defaults of [MinioBuilder].
+/// ## Example
+/// ```rust
+/// use sc_minio::client::{MinioBuilder, MinioConfig};
+/// use sc_minio::provider::StaticProvider;
+///
+/// let config: MinioConfig = serde_json::from_str(
+///     r#"{"endpoint": "localhost:9000", "secure": false, "region": "eu-west-1"}"#,
+/// )
+/// .unwrap();
+/// let minio = MinioBuilder::from_config(config)
+///     .provider(StaticProvider::new("access", "secret", None))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct MinioConfig {
+    pub endpoint: Option<String>,
+    pub region: String,
+    pub agent: String,
+    pub secure: bool,
+    pub virtual_hosted_style: bool,
+    pub path_prefix: String,
+    pub multi_chunked_encoding: bool,
+    pub unsigned_payload: bool,
+    pub part_size: usize,
+    pub min_part_size: usize,
+    pub max_inflight_parts: usize,
+    pub max_object_size: usize,
+    pub max_error_body_bytes: usize,
+    pub resume_retries: usize,
+    pub pool_max_idle_per_host: usize,
+    /// Timeout in seconds for idle sockets, `None` to disable timeout.
+    pub pool_idle_timeout_secs: Option<u64>,
+    pub http2_prior_knowledge: bool,
+    pub danger_accept_invalid_certs: bool,
+    pub check_object_name: bool,
+    pub signature_version: SignatureVersion,
+    pub default_bucket: Option<String>,
+}
+
+impl Default for MinioConfig {
+    fn default() -> Self {
+        let builder = MinioBuilder::new();
+        Self {
+            endpoint: builder.endpoint,
+            region: builder.region,
+            agent: builder.agent,
+            secure: builder.secure,
+            virtual_hosted_style: builder.virtual_hosted,
+            path_prefix: builder.path_prefix,
+            multi_chunked_encoding: builder.multi_chunked_encoding,
+            unsigned_payload: builder.unsigned_payload,
+            part_size: builder.part_size,
+            min_part_size: builder.min_part_size,
+            max_inflight_parts: builder.max_inflight_parts,
+            max_object_size: builder.max_object_size,
+            max_error_body_bytes: builder.max_error_body_bytes,
+            resume_retries: builder.resume_retries,
+            pool_max_idle_per_host: builder.pool_max_idle_per_host,
+            pool_idle_timeout_secs: builder.pool_idle_timeout.map(|x| x.as_secs()),
+            http2_prior_knowledge: builder.http2_prior_knowledge,
+            danger_accept_invalid_certs: builder.danger_accept_invalid_certs,
+            check_object_name: builder.check_object_name,
+            signature_version: builder.signature_version,
+            default_bucket: builder.default_bucket,
+        }
+    }
 }
 
 impl MinioBuilder {
@@ -34,14 +138,61 @@ impl MinioBuilder {
             endpoint: None,
             secure: true,
             virtual_hosted: false,
+            path_prefix: String::new(),
             multi_chunked_encoding: true,
+            unsigned_payload: false,
+            part_size: MIN_PART_SIZE,
+            min_part_size: MIN_PART_SIZE,
+            max_inflight_parts: 1,
+            max_object_size: MAX_MULTIPART_OBJECT_SIZE,
+            max_error_body_bytes: 1024 * 1024,
+            resume_retries: 3,
             region: "us-east-1".to_string(),
             agent: "MinIO (Linux; x86_64) minio-rs".to_string(),
             provider: None,
             client: None,
+            pool_max_idle_per_host: usize::MAX,
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            http2_prior_knowledge: false,
+            proxies: Vec::new(),
+            danger_accept_invalid_certs: false,
+            root_certificates: Vec::new(),
+            check_object_name: true,
+            signature_version: SignatureVersion::V4,
+            clock: None,
+            default_bucket: None,
+            #[cfg(feature = "mock")]
+            transport: None,
         }
     }
 
+    /// Create a builder from a [MinioConfig], the credential provider must still be set.
+    pub fn from_config(config: MinioConfig) -> Self {
+        let mut builder = MinioBuilder::new()
+            .region(config.region)
+            .agent(config.agent)
+            .secure(config.secure)
+            .virtual_hosted_style(config.virtual_hosted_style)
+            .path_prefix(config.path_prefix)
+            .multi_chunked_encoding(config.multi_chunked_encoding)
+            .unsigned_payload(config.unsigned_payload)
+            .part_size(config.part_size)
+            .min_part_size(config.min_part_size)
+            .max_inflight_parts(config.max_inflight_parts)
+            .max_object_size(config.max_object_size)
+            .max_error_body_bytes(config.max_error_body_bytes)
+            .resume_retries(config.resume_retries)
+            .pool_max_idle_per_host(config.pool_max_idle_per_host)
+            .pool_idle_timeout(config.pool_idle_timeout_secs.map(Duration::from_secs))
+            .http2_prior_knowledge(config.http2_prior_knowledge)
+            .danger_accept_invalid_certs(config.danger_accept_invalid_certs)
+            .check_object_name(config.check_object_name)
+            .signature_version(config.signature_version);
+        builder.endpoint = config.endpoint;
+        builder.default_bucket = config.default_bucket;
+        builder
+    }
+
     /// Set hostname of a S3 service.
     #[deprecated(note = "Please use the `endpoint` instead")]
     pub fn host<T: Into<String>>(mut self, host: T) -> Self {
@@ -59,6 +210,8 @@ impl MinioBuilder {
     }
 
     /// Set endpoint of a S3 service. `hostname`
+    ///
+    /// If the endpoint starts with `http://` or `https://`, the scheme takes precedence over [secure](Self::secure).
     pub fn endpoint<T: Into<String>>(mut self, endpoint: T) -> Self {
         self.endpoint = Some(endpoint.into());
         self
@@ -72,6 +225,32 @@ impl MinioBuilder {
         self
     }
 
+    /// Set region name from the `AWS_REGION` or `AWS_DEFAULT_REGION` environment variable,
+    /// the region is unchanged if neither is set.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sc_minio::provider::StaticProvider;
+    /// use sc_minio::Minio;
+    ///
+    /// let minio = Minio::builder()
+    ///     .endpoint("s3.amazonaws.com")
+    ///     .provider(StaticProvider::from_env_aws().unwrap_or(StaticProvider::new("", "", None)))
+    ///     .region_from_env()
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn region_from_env(mut self) -> Self {
+        let region = region_from_vars(
+            std::env::var("AWS_REGION").ok(),
+            std::env::var("AWS_DEFAULT_REGION").ok(),
+        );
+        if let Some(region) = region {
+            self.region = region;
+        }
+        self
+    }
+
     /// Set agent header for minio client.
     ///
     /// Default: `MinIO (Linux; x86_64) minio-rs`
@@ -80,6 +259,16 @@ impl MinioBuilder {
         self
     }
 
+    /// Append an identifier of the application to the agent header, separated by a space,
+    /// such as `myapp/1.2.3`.
+    ///
+    /// Unlike [agent](Self::agent), the default agent is kept.
+    pub fn append_agent(mut self, agent: &str) -> Self {
+        self.agent.push(' ');
+        self.agent.push_str(agent);
+        self
+    }
+
     /// Set flag to indicate to use secure (TLS) connection to S3 service or not.
     ///
     /// Default: `true`.
@@ -94,12 +283,94 @@ impl MinioBuilder {
         self
     }
 
+    /// Set custom [Transport] to send the signed requests, such as [MockTransport](crate::transport::MockTransport).
+    ///
+    /// **Note**: The [reqwest::Client] is not used if a custom transport is set.
+    #[cfg(feature = "mock")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+    pub fn transport<T>(mut self, transport: T) -> Self
+    where
+        T: Transport + 'static,
+    {
+        self.transport = Some(Box::new(transport));
+        self
+    }
+
+    /// Set the maximum idle connection per host allowed in the pool.
+    ///
+    /// Default: `usize::MAX` (no limit).
+    ///
+    /// **Note**: Ignored if a custom [reqwest::Client] is set.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = max;
+        self
+    }
+
+    /// Set an optional timeout for idle sockets being kept-alive.
+    /// Pass `None` to disable timeout.
+    ///
+    /// Default: 90 seconds.
+    ///
+    /// **Note**: Ignored if a custom [reqwest::Client] is set.
+    pub fn pool_idle_timeout<D: Into<Option<Duration>>>(mut self, timeout: D) -> Self {
+        self.pool_idle_timeout = timeout.into();
+        self
+    }
+
+    /// Set flag to indicate to only use HTTP/2 or not.
+    ///
+    /// Default: `false`.
+    ///
+    /// **Note**: Ignored if a custom [reqwest::Client] is set.
+    pub fn http2_prior_knowledge(mut self, http2_prior_knowledge: bool) -> Self {
+        self.http2_prior_knowledge = http2_prior_knowledge;
+        self
+    }
+
+    /// Add a [reqwest::Proxy] to the list of proxies the client will use.
+    ///
+    /// If no proxy is set, the `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY`
+    /// environment variables are honored.
+    ///
+    /// **Note**: Ignored if a custom [reqwest::Client] is set.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Set flag to indicate to skip TLS certificate verification or not.
+    ///
+    /// Default: `false`.
+    ///
+    /// **Warning**: You should think very carefully before using this method.
+    /// If invalid certificates are trusted, *any* certificate for *any* site will be
+    /// trusted for use, this includes expired certificates. This introduces significant
+    /// vulnerabilities, such as man-in-the-middle attacks. Prefer [MinioBuilder::add_root_certificate]
+    /// for a self-signed certificate.
+    ///
+    /// **Note**: Ignored if a custom [reqwest::Client] is set.
+    pub fn danger_accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.danger_accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    /// Add a custom root certificate, like the CA of a self-hosted MinIO.
+    ///
+    /// **Note**: Ignored if a custom [reqwest::Client] is set.
+    pub fn add_root_certificate(mut self, cert: reqwest::Certificate) -> Self {
+        self.root_certificates.push(cert);
+        self
+    }
+
     /// Set flag to indicate to use Virtual-hosted–style or not.
     ///
     /// In a virtual-hosted–style URI, the bucket name is part of the domain name in the URL.
     /// like `https://bucket-name.s3.region-code.amazonaws.com`
     ///
-    /// Default: `false`.
+    /// Default: `false`, all requests use path-style, like `https://s3.region-code.amazonaws.com/bucket-name`.
+    ///
+    /// Buckets with dots in the name always use path-style,
+    /// since `a.b.s3.amazonaws.com` does not match the wildcard TLS certificate of the endpoint.
     ///
     /// **Note**: If the endpoint is an IP address, setting Virtual-hosted–style true will cause an error.
     pub fn virtual_hosted_style(mut self, virtual_hosted_style: bool) -> Self {
@@ -107,6 +378,123 @@ impl MinioBuilder {
         self
     }
 
+    /// Set the path prefix inserted between the endpoint and the bucket,
+    /// for S3 services served under a sub path, like `https://example.com/minio/bucket-name`.
+    ///
+    /// Default: empty.
+    pub fn path_prefix<T: Into<String>>(mut self, path_prefix: T) -> Self {
+        let path_prefix: String = path_prefix.into();
+        let path_prefix = path_prefix.trim_matches('/');
+        self.path_prefix = if path_prefix.is_empty() {
+            String::new()
+        } else {
+            format!("/{path_prefix}")
+        };
+        self
+    }
+
+    /// Set the part size of multipart upload, must be at least the [min_part_size](Self::min_part_size).
+    ///
+    /// Default: `5MiB`.
+    pub fn part_size(mut self, part_size: usize) -> Self {
+        self.part_size = part_size;
+        self
+    }
+
+    /// Set the minimum part size accepted by the S3 service, must be positive.
+    ///
+    /// Default: `5MiB`, the limit of AWS S3.
+    pub fn min_part_size(mut self, min_part_size: usize) -> Self {
+        self.min_part_size = min_part_size;
+        self
+    }
+
+    /// Set the maximum number of parts uploaded at the same time when uploading a stream by multipart upload.
+    ///
+    /// The stream is not read while the limit is reached, so at most
+    /// `(max_inflight_parts + 1) * part_size` bytes are buffered.
+    ///
+    /// Default: `1`, parts are uploaded one by one.
+    pub fn max_inflight_parts(mut self, max_inflight_parts: usize) -> Self {
+        self.max_inflight_parts = max_inflight_parts;
+        self
+    }
+
+    /// Set the maximum object size accepted by the S3 service, must be positive.
+    ///
+    /// Default: `5TiB`, the limit of AWS S3.
+    pub fn max_object_size(mut self, max_object_size: usize) -> Self {
+        self.max_object_size = max_object_size;
+        self
+    }
+
+    /// Set the maximum bytes of an error response body buffered to parse the [S3Error](crate::error::S3Error),
+    /// the rest of the body is discarded.
+    ///
+    /// Guards against endpoints returning huge error pages, the body of a successful response is not limited.
+    ///
+    /// Default: `1MiB`.
+    pub fn max_error_body_bytes(mut self, max_error_body_bytes: usize) -> Self {
+        self.max_error_body_bytes = max_error_body_bytes;
+        self
+    }
+
+    /// Set the maximum times a download with [KeyArgs::resilient](super::KeyArgs::resilient) is resumed
+    /// after the connection drops in the middle of the response.
+    ///
+    /// Default: `3`.
+    pub fn resume_retries(mut self, resume_retries: usize) -> Self {
+        self.resume_retries = resume_retries;
+        self
+    }
+
+    /// Set the clock used as the date of signatures, for deterministic signing in tests.
+    ///
+    /// Default: the system clock.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use sc_minio::{provider::StaticProvider, time::UtcTime, Minio};
+    ///
+    /// let minio = Minio::builder()
+    ///     .endpoint("localhost:9000")
+    ///     .provider(StaticProvider::new("access", "secret", None))
+    ///     .clock(|| UtcTime::new(Utc.with_ymd_and_hms(2013, 5, 24, 0, 0, 0).unwrap()))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn clock<F>(mut self, clock: F) -> Self
+    where
+        F: Fn() -> UtcTime + Send + Sync + 'static,
+    {
+        self.clock = Some(Box::new(clock));
+        self
+    }
+
+    /// Set the default bucket of the client, used by [Minio::default_bucket].
+    ///
+    /// For applications working with a single bucket.
+    /// ## Example
+    /// ```rust
+    /// use sc_minio::{provider::StaticProvider, Minio};
+    ///
+    /// # async fn example() -> sc_minio::error::Result<()> {
+    /// let minio = Minio::builder()
+    ///     .endpoint("localhost:9000")
+    ///     .provider(StaticProvider::new("access", "secret", None))
+    ///     .default_bucket("bucket")
+    ///     .build()
+    ///     .unwrap();
+    /// let bucket = minio.default_bucket()?;
+    /// let text = bucket.get_object("file.txt").await?.text().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn default_bucket<T: Into<String>>(mut self, bucket: T) -> Self {
+        self.default_bucket = Some(bucket.into());
+        self
+    }
+
     /// Set flag to indicate to use multi_chunked_encoding or not.
     ///
     /// Default: `true`.
@@ -115,6 +503,40 @@ impl MinioBuilder {
         self
     }
 
+    /// Set flag to indicate to send the stream of uploads as is with `x-amz-content-sha256: UNSIGNED-PAYLOAD`,
+    /// instead of signing every chunk, or buffering the whole body when multi_chunked_encoding is disabled.
+    ///
+    /// The payload is not protected by the signature, enable it only with TLS.
+    ///
+    /// Default: `false`.
+    pub fn unsigned_payload(mut self, unsigned_payload: bool) -> Self {
+        self.unsigned_payload = unsigned_payload;
+        self
+    }
+
+    /// Set flag to indicate to validate object names before sending requests or not.
+    ///
+    /// When enabled, object names longer than 1024 bytes or containing
+    /// ASCII control characters are rejected with a [ValueError].
+    ///
+    /// Default: `true`.
+    pub fn check_object_name(mut self, check_object_name: bool) -> Self {
+        self.check_object_name = check_object_name;
+        self
+    }
+
+    /// Set the signature version used to sign requests.
+    ///
+    /// [SignatureVersion::V2] is only for legacy S3-compatible stores that reject signature V4,
+    /// and streaming payloads are sent without `aws-chunked` encoding.
+    /// Presigned URLs are always signed with signature V4.
+    ///
+    /// Default: [SignatureVersion::V4].
+    pub fn signature_version(mut self, signature_version: SignatureVersion) -> Self {
+        self.signature_version = signature_version;
+        self
+    }
+
     /// Set credentials provider of your account in S3 service.
     ///
     /// **Required**.
@@ -128,10 +550,29 @@ impl MinioBuilder {
 
     pub fn build(self) -> std::result::Result<Minio, ValueError> {
         let endpoint = self.endpoint.ok_or("Miss endpoint")?;
+        let (endpoint, secure) = if let Some(endpoint) = endpoint.strip_prefix("http://") {
+            (endpoint.to_string(), false)
+        } else if let Some(endpoint) = endpoint.strip_prefix("https://") {
+            (endpoint.to_string(), true)
+        } else {
+            (endpoint, self.secure)
+        };
         if !_VALID_ENDPOINT.is_match(&endpoint) {
             return Err("Invalid endpoint".into());
         }
         let provider = self.provider.ok_or("Miss provide")?;
+        if self.min_part_size == 0 {
+            return Err("min part size must be positive".into());
+        }
+        if self.max_object_size == 0 {
+            return Err("max object size must be positive".into());
+        }
+        if self.part_size < self.min_part_size || self.part_size > MAX_PART_SIZE {
+            return Err("part size must be between the min part size and 5GiB".into());
+        }
+        if self.max_inflight_parts == 0 {
+            return Err("max inflight parts must be positive".into());
+        }
 
         let agent: HeaderValue = self
             .agent
@@ -141,21 +582,51 @@ impl MinioBuilder {
         let client2 = self.client.unwrap_or_else(|| {
             let mut headers = header::HeaderMap::new();
             headers.insert(header::USER_AGENT, agent.clone());
-            reqwest::Client::builder()
+            let mut builder = reqwest::Client::builder()
                 .default_headers(headers)
-                .https_only(self.secure)
+                .https_only(secure)
                 .max_tls_version(reqwest::tls::Version::TLS_1_2)
-                .build()
-                .unwrap()
+                .pool_max_idle_per_host(self.pool_max_idle_per_host)
+                .pool_idle_timeout(self.pool_idle_timeout);
+            if self.http2_prior_knowledge {
+                builder = builder.http2_prior_knowledge();
+            }
+            for proxy in self.proxies {
+                builder = builder.proxy(proxy);
+            }
+            for cert in self.root_certificates {
+                builder = builder.add_root_certificate(cert);
+            }
+            if self.danger_accept_invalid_certs {
+                builder = builder.danger_accept_invalid_certs(true);
+            }
+            builder.build().unwrap()
         });
         Ok(Minio {
+            region: None,
             inner: Arc::new(MinioRef {
                 endpoint,
-                secure: self.secure,
+                secure,
                 client2,
                 virtual_hosted: self.virtual_hosted,
+                path_prefix: self.path_prefix,
+                part_size: self.part_size,
+                min_part_size: self.min_part_size,
+                max_inflight_parts: self.max_inflight_parts,
+                max_object_size: self.max_object_size,
+                max_error_body_bytes: self.max_error_body_bytes,
+                resume_retries: self.resume_retries,
                 multi_chunked: self.multi_chunked_encoding,
+                unsigned_payload: self.unsigned_payload,
+                check_object_name: self.check_object_name,
+                signature_version: self.signature_version,
+                clock: self.clock,
+                default_bucket: self.default_bucket,
+                #[cfg(feature = "mock")]
+                transport: self.transport,
                 region: self.region,
+                region_cache: Default::default(),
+                clock_skew: Default::default(),
                 agent,
                 provider,
             }),
@@ -163,6 +634,14 @@ impl MinioBuilder {
     }
 }
 
+/// pick the region from the values of `AWS_REGION` and `AWS_DEFAULT_REGION`, empty values are ignored.
+fn region_from_vars(aws_region: Option<String>, default_region: Option<String>) -> Option<String> {
+    [aws_region, default_region]
+        .into_iter()
+        .flatten()
+        .find(|region| !region.is_empty())
+}
+
 /// Simple Storage Service (aka S3) client to perform bucket and object operations.
 ///
 /// You do **not** have to wrap the `Minio` in an [`Rc`] or [`Arc`] to **reuse** it,
@@ -183,15 +662,35 @@ impl MinioBuilder {
 #[derive(Clone)]
 pub struct Minio {
     inner: Arc<MinioRef>,
+    /// the region set by [Minio::with_region].
+    region: Option<String>,
 }
 
 struct MinioRef {
     endpoint: String,
     virtual_hosted: bool,
+    path_prefix: String,
+    part_size: usize,
+    min_part_size: usize,
+    max_inflight_parts: usize,
+    max_object_size: usize,
+    max_error_body_bytes: usize,
+    resume_retries: usize,
     multi_chunked: bool,
+    unsigned_payload: bool,
+    check_object_name: bool,
+    signature_version: SignatureVersion,
+    clock: Option<Box<Clock>>,
+    default_bucket: Option<String>,
     secure: bool,
     client2: reqwest::Client,
+    #[cfg(feature = "mock")]
+    transport: Option<Box<dyn Transport>>,
     region: String,
+    /// regions of buckets learned from redirects.
+    region_cache: RwLock<HashMap<String, String>>,
+    /// offset of the server clock, learned from a `RequestTimeTooSkewed` error.
+    clock_skew: RwLock<Option<chrono::Duration>>,
     agent: HeaderValue,
     provider: Box<dyn Provider>,
 }
@@ -207,12 +706,122 @@ impl Minio {
         self.inner.multi_chunked
     }
 
+    /// return whether the minio streams uploads with unsigned payload.
+    pub(crate) fn unsigned_payload(&self) -> bool {
+        self.inner.unsigned_payload
+    }
+
+    /// return the current time of the clock, corrected by the [clock_skew](Self::clock_skew).
+    pub(crate) fn now(&self) -> UtcTime {
+        let now = self.local_now();
+        match self.clock_skew() {
+            Some(skew) => UtcTime::new(now.datetime() + skew),
+            None => now,
+        }
+    }
+
+    /// return the current time of the clock.
+    fn local_now(&self) -> UtcTime {
+        match &self.inner.clock {
+            Some(clock) => clock(),
+            None => UtcTime::now(),
+        }
+    }
+
+    /// Return the offset of the server clock to the local clock, if a request has failed
+    /// with `RequestTimeTooSkewed`.
+    ///
+    /// The offset is added to the local time when signing the subsequent requests.
+    pub fn clock_skew(&self) -> Option<chrono::Duration> {
+        *self.inner.clock_skew.read().unwrap()
+    }
+
+    /// remember the offset of the server clock from the time of the server.
+    pub(crate) fn _set_server_time(&self, server_time: UtcTime) {
+        let skew = server_time.datetime() - self.local_now().datetime();
+        *self.inner.clock_skew.write().unwrap() = Some(skew);
+    }
+
+    /// return the part size of multipart upload.
+    pub(crate) fn part_size(&self) -> usize {
+        self.inner.part_size
+    }
+
+    /// return the minimum part size accepted by the S3 service.
+    pub(crate) fn min_part_size(&self) -> usize {
+        self.inner.min_part_size
+    }
+
+    /// return the maximum number of parts uploaded at the same time.
+    pub(crate) fn max_inflight_parts(&self) -> usize {
+        self.inner.max_inflight_parts
+    }
+
+    /// return the maximum object size accepted by the S3 service.
+    pub(crate) fn max_object_size(&self) -> usize {
+        self.inner.max_object_size
+    }
+
+    /// return the maximum times a resilient download is resumed.
+    pub(crate) fn resume_retries(&self) -> usize {
+        self.inner.resume_retries
+    }
+
+    /// read the [S3Error] of a failed response, at most `max_error_body_bytes` of the body are buffered.
+    pub(crate) async fn _read_error(&self, mut res: Response) -> Result<S3Error> {
+        let limit = self.inner.max_error_body_bytes;
+        let mut body = BytesMut::new();
+        while let Some(chunk) = res.chunk().await? {
+            let remain = limit - body.len();
+            if chunk.len() >= remain {
+                body.extend_from_slice(&chunk[..remain]);
+                break;
+            }
+            body.extend_from_slice(&chunk);
+        }
+        let err = S3Error::try_from(body.as_ref())?;
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_s3_error(&err.code);
+        Ok(err)
+    }
+
     pub fn region(&self) -> &str {
         self.inner.region.as_ref()
     }
 
-    fn _get_region<T: Into<String>>(&self, bucket_name: Option<T>) -> String {
-        self.inner.region.clone()
+    /// return the region of the bucket if it is learned from a redirect, otherwise the region of the client.
+    pub(crate) fn _get_region(&self, bucket_name: Option<&str>) -> String {
+        if let Some(region) = &self.region {
+            return region.clone();
+        }
+        bucket_name
+            .and_then(|b| self.inner.region_cache.read().unwrap().get(b).cloned())
+            .unwrap_or_else(|| self.inner.region.clone())
+    }
+
+    /// remember the region of the bucket.
+    pub(crate) fn _set_region(&self, bucket_name: &str, region: &str) {
+        self.inner
+            .region_cache
+            .write()
+            .unwrap()
+            .insert(bucket_name.to_string(), region.to_string());
+    }
+
+    /// return the endpoint for the bucket.
+    ///
+    /// A regional AWS endpoint like `s3.us-east-1.amazonaws.com` is replaced by
+    /// the endpoint of the region where the bucket resides.
+    fn _get_endpoint(&self, bucket_name: Option<&str>) -> String {
+        let endpoint = self.inner.endpoint.as_str();
+        let default = format!("s3.{}.amazonaws.com", self.inner.region);
+        if endpoint == default {
+            let region = self._get_region(bucket_name);
+            if region != self.inner.region {
+                return format!("s3.{region}.amazonaws.com");
+            }
+        }
+        endpoint.to_string()
     }
 
     #[inline]
@@ -233,10 +842,16 @@ impl Minio {
             .client2
             .request(method, uri)
             .headers(headers)
-            .body(body)
-            .send()
-            .await?;
-        Ok(request)
+            .body(body);
+        self._send(request).await
+    }
+
+    async fn _send(&self, request: reqwest::RequestBuilder) -> Result<Response> {
+        #[cfg(feature = "mock")]
+        if let Some(transport) = &self.inner.transport {
+            return transport.send(request.build()?).await;
+        }
+        Ok(request.send().await?)
     }
 
     #[inline]
@@ -248,18 +863,24 @@ impl Minio {
         }
     }
 
+    /// Return true if the requests of the bucket use Virtual-hosted–style.
+    fn is_virtual_hosted(&self, bucket_name: &str) -> bool {
+        self.inner.virtual_hosted && !bucket_name.contains('.')
+    }
+
     /// build uri for bucket/key
     ///
     /// uriencode(key)
     pub fn _build_uri(&self, bucket: Option<String>, key: Option<String>) -> String {
         let scheme = self.scheme();
-        let endpoint = self.inner.endpoint.as_str();
+        let endpoint = self._get_endpoint(bucket.as_deref());
+        let prefix = self.inner.path_prefix.as_str();
         match bucket {
             Some(b) => {
-                let mut uri = if self.inner.virtual_hosted {
-                    format!("{scheme}://{b}.{endpoint}")
+                let mut uri = if self.is_virtual_hosted(&b) {
+                    format!("{scheme}://{b}.{endpoint}{prefix}")
                 } else {
-                    format!("{scheme}://{endpoint}/{b}",)
+                    format!("{scheme}://{endpoint}{prefix}/{b}",)
                 };
                 if let Some(key) = key {
                     uri.push('/');
@@ -267,20 +888,18 @@ impl Minio {
                 }
                 uri
             }
-            None => format!("{scheme}://{endpoint}"),
+            None if prefix.is_empty() => format!("{scheme}://{endpoint}"),
+            None => format!("{scheme}://{endpoint}{prefix}/"),
         }
     }
 
-    pub async fn _execute<B: Into<Data<crate::error::Error>>>(
+    /// check bucket/object name and build uri with query string.
+    pub(super) fn _build_request_uri(
         &self,
-        method: Method,
-        region: &str,
         bucket_name: Option<String>,
         object_name: Option<String>,
-        data: B,
-        headers: Option<HeaderMap>,
         query_params: Option<String>,
-    ) -> Result<Response> {
+    ) -> Result<Uri> {
         // check bucket_name
         if let Some(bucket_name) = &bucket_name {
             check_bucket_name(bucket_name)?;
@@ -290,6 +909,9 @@ impl Minio {
             if object_name.is_empty() {
                 Err(ValueError::from("Object name cannot be empty."))?
             }
+            if self.inner.check_object_name {
+                check_object_name(object_name)?;
+            }
             if bucket_name.is_none() {
                 Err(ValueError::from("Miss bucket name."))?
             }
@@ -303,24 +925,103 @@ impl Minio {
         } else {
             uri
         };
-        let mut data = data.into();
-        if !self.inner.multi_chunked {
+        Uri::from_str(&uri).map_err(|e| Error::ValueError(e.to_string()))
+    }
+
+    pub async fn _execute<B: Into<Data<crate::error::Error>>>(
+        &self,
+        method: Method,
+        region: &str,
+        bucket_name: Option<String>,
+        object_name: Option<String>,
+        data: B,
+        headers: Option<HeaderMap>,
+        query_params: Option<String>,
+    ) -> Result<Response> {
+        let data = data.into();
+        #[cfg(feature = "metrics")]
+        let (len, operation) = (
+            data.len(),
+            crate::metrics::operation_name(
+                &method,
+                bucket_name.is_some(),
+                object_name.is_some(),
+                headers.as_ref(),
+                query_params.as_deref().unwrap_or_default(),
+            ),
+        );
+        let request = self
+            ._build_request(
+                method,
+                region,
+                bucket_name,
+                object_name,
+                data,
+                headers,
+                query_params,
+            )
+            .await?;
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_upload(len);
+        #[cfg(feature = "metrics")]
+        let metrics = crate::metrics::RequestMetrics::start(operation);
+        let res = self
+            ._url_open(request.method, request.uri, request.headers, request.body)
+            .await;
+        #[cfg(feature = "metrics")]
+        metrics.finish(&res);
+        res
+    }
+
+    /// Sign the request without sending it.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn _build_request(
+        &self,
+        method: Method,
+        region: &str,
+        bucket_name: Option<String>,
+        object_name: Option<String>,
+        mut data: Data<crate::error::Error>,
+        headers: Option<HeaderMap>,
+        query_params: Option<String>,
+    ) -> Result<BuiltRequest> {
+        let virtual_host_bucket = bucket_name.clone().filter(|b| self.is_virtual_hosted(b));
+        let uri = self._build_request_uri(bucket_name, object_name, query_params)?;
+        if !self.inner.multi_chunked && !self.inner.unsigned_payload {
             data = data.convert().await?;
         }
         let mut headers = headers.unwrap_or(HeaderMap::new());
         headers.insert(header::USER_AGENT, self.inner.agent.clone());
         let credentials = self.fetch_credentials().await;
-        let uri = Uri::from_str(&uri).map_err(|e| Error::ValueError(e.to_string()))?;
-        let (uri, body) = sign_request_v4(
-            &method,
-            &uri,
-            &mut headers,
-            region,
-            data,
-            credentials.access_key(),
-            credentials.secret_key(),
-        )?;
-        self._url_open(method, uri, headers, body).await
+        let (uri, body) = match self.inner.signature_version {
+            SignatureVersion::V2 => _sign_request_v2(
+                &method,
+                &uri,
+                &mut headers,
+                data,
+                credentials.access_key(),
+                credentials.secret_key(),
+                virtual_host_bucket.as_deref(),
+                self.now(),
+            )?,
+            SignatureVersion::V4 => _sign_request_v4(
+                &method,
+                &uri,
+                &mut headers,
+                region,
+                data,
+                credentials.access_key(),
+                credentials.secret_key(),
+                self.now(),
+                self.inner.unsigned_payload,
+            )?,
+        };
+        Ok(BuiltRequest {
+            method,
+            uri,
+            headers,
+            body,
+        })
     }
 
     #[inline]
@@ -338,4 +1039,338 @@ impl Minio {
             bucket: bucket.into(),
         }
     }
+
+    /// Return a client signing requests for the region, which shares the connections,
+    /// credentials and configuration of this client.
+    ///
+    /// The region overrides the region of the client and the regions of buckets learned from redirects,
+    /// a region set on [BucketArgs] still takes precedence.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// # async fn example(minio: Minio){
+    /// let eu = minio.with_region("eu-west-1");
+    /// let exists = eu.bucket_exists("bucket").await.unwrap();
+    /// # }
+    /// ```
+    pub fn with_region<T: Into<String>>(&self, region: T) -> Minio {
+        Minio {
+            inner: self.inner.clone(),
+            region: Some(region.into()),
+        }
+    }
+
+    /// Instantiate an [Bucket] of the default bucket set by [MinioBuilder::default_bucket].
+    ///
+    /// Return a [ValueError] if the default bucket is not set.
+    pub fn default_bucket(&self) -> Result<Bucket> {
+        match &self.inner.default_bucket {
+            Some(bucket) => Ok(self.bucket(bucket.as_str())),
+            None => Err(ValueError::from("the default bucket is not set").into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::{HeaderMap, Method};
+
+    use super::{region_from_vars, MinioBuilder, MinioConfig};
+    use crate::provider::StaticProvider;
+    use crate::signer::_get_canonical_request;
+    use crate::{Minio, SignatureVersion};
+
+    #[test]
+    fn test_path_prefix() {
+        let minio = Minio::builder()
+            .endpoint("localhost:9022")
+            .secure(false)
+            .path_prefix("/minio/")
+            .provider(StaticProvider::new("access", "secret", None))
+            .build()
+            .unwrap();
+        assert_eq!(
+            minio._build_uri(Some("bucket".into()), Some("dir/file.txt".into())),
+            "http://localhost:9022/minio/bucket/dir/file.txt"
+        );
+        assert_eq!(minio._build_uri(None, None), "http://localhost:9022/minio/");
+
+        let uri = minio
+            ._build_request_uri(Some("bucket".into()), Some("file.txt".into()), None)
+            .unwrap();
+        let (canonical_request, _) =
+            _get_canonical_request(&Method::GET, &uri, &HeaderMap::new(), "UNSIGNED-PAYLOAD");
+        assert_eq!(
+            canonical_request.lines().nth(1),
+            Some("/minio/bucket/file.txt")
+        );
+    }
+
+    #[test]
+    fn test_object_name_encoding() {
+        let minio = Minio::builder()
+            .endpoint("localhost:9022")
+            .secure(false)
+            .provider(StaticProvider::new("access", "secret", None))
+            .build()
+            .unwrap();
+        for (key, path) in [
+            ("my file (1).txt", "/bucket/my%20file%20%281%29.txt"),
+            ("файл.txt", "/bucket/%D1%84%D0%B0%D0%B9%D0%BB.txt"),
+            ("a+b%c.txt", "/bucket/a%2Bb%25c.txt"),
+            ("dir/sub dir/~file.txt", "/bucket/dir/sub%20dir/~file.txt"),
+            ("already%20encoded.txt", "/bucket/already%2520encoded.txt"),
+        ] {
+            let uri = minio
+                ._build_request_uri(Some("bucket".into()), Some(key.into()), None)
+                .unwrap();
+            let (canonical_request, _) =
+                _get_canonical_request(&Method::GET, &uri, &HeaderMap::new(), "UNSIGNED-PAYLOAD");
+            assert_eq!(canonical_request.lines().nth(1), Some(path));
+            // the URL sent by reqwest must keep the path that was signed.
+            let url = reqwest::Url::parse(&uri.to_string()).unwrap();
+            assert_eq!(url.path(), path);
+        }
+    }
+
+    #[test]
+    fn test_region_endpoint() {
+        let minio = Minio::builder()
+            .endpoint("s3.us-east-1.amazonaws.com")
+            .provider(StaticProvider::new("access", "secret", None))
+            .build()
+            .unwrap();
+        minio._set_region("moved", "eu-west-1");
+        assert_eq!(minio._get_region(Some("moved")), "eu-west-1");
+        assert_eq!(minio._get_region(Some("bucket")), "us-east-1");
+        assert_eq!(
+            minio._build_uri(Some("moved".into()), None),
+            "https://s3.eu-west-1.amazonaws.com/moved"
+        );
+        assert_eq!(
+            minio._build_uri(Some("bucket".into()), None),
+            "https://s3.us-east-1.amazonaws.com/bucket"
+        );
+    }
+
+    #[test]
+    fn test_with_region() {
+        let minio = Minio::builder()
+            .endpoint("s3.us-east-1.amazonaws.com")
+            .provider(StaticProvider::new("access", "secret", None))
+            .build()
+            .unwrap();
+        minio._set_region("moved", "ap-south-1");
+        let eu = minio.with_region("eu-west-1");
+        assert_eq!(eu._get_region(Some("bucket")), "eu-west-1");
+        assert_eq!(eu._get_region(Some("moved")), "eu-west-1");
+        assert_eq!(
+            eu._build_uri(Some("bucket".into()), None),
+            "https://s3.eu-west-1.amazonaws.com/bucket"
+        );
+        assert_eq!(minio._get_region(Some("bucket")), "us-east-1");
+        assert_eq!(minio._get_region(Some("moved")), "ap-south-1");
+    }
+
+    #[test]
+    fn test_region_from_vars() {
+        let var = |v: &str| Some(v.to_string());
+        assert_eq!(region_from_vars(None, None), None);
+        assert_eq!(region_from_vars(var(""), var("")), None);
+        assert_eq!(region_from_vars(None, var("ap-south-1")), var("ap-south-1"));
+        assert_eq!(
+            region_from_vars(var(""), var("ap-south-1")),
+            var("ap-south-1")
+        );
+        assert_eq!(
+            region_from_vars(var("us-west-2"), var("ap-south-1")),
+            var("us-west-2")
+        );
+    }
+
+    #[test]
+    fn test_append_agent() {
+        let minio = Minio::builder()
+            .endpoint("localhost:9000")
+            .provider(StaticProvider::new("access", "secret", None))
+            .append_agent("myapp/1.2.3")
+            .build()
+            .unwrap();
+        assert_eq!(
+            minio.inner.agent,
+            "MinIO (Linux; x86_64) minio-rs myapp/1.2.3"
+        );
+        let minio = Minio::builder()
+            .endpoint("localhost:9000")
+            .provider(StaticProvider::new("access", "secret", None))
+            .agent("myapp")
+            .append_agent("plugin/0.1")
+            .build()
+            .unwrap();
+        assert_eq!(minio.inner.agent, "myapp plugin/0.1");
+        assert!(Minio::builder()
+            .endpoint("localhost:9000")
+            .provider(StaticProvider::new("access", "secret", None))
+            .append_agent("my\napp")
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_default_bucket() {
+        let minio = Minio::builder()
+            .endpoint("localhost:9000")
+            .provider(StaticProvider::new("access", "secret", None))
+            .build()
+            .unwrap();
+        assert!(minio.default_bucket().is_err());
+        let minio = Minio::builder()
+            .endpoint("localhost:9000")
+            .provider(StaticProvider::new("access", "secret", None))
+            .default_bucket("bucket")
+            .build()
+            .unwrap();
+        assert_eq!(minio.default_bucket().unwrap().bucket_args().name, "bucket");
+    }
+
+    #[test]
+    fn test_ipv6_endpoint() {
+        let minio = Minio::builder()
+            .endpoint("[::1]:9000")
+            .secure(false)
+            .provider(StaticProvider::new("access", "secret", None))
+            .build()
+            .unwrap();
+        assert_eq!(
+            minio._build_uri(Some("bucket".into()), Some("file.txt".into())),
+            "http://[::1]:9000/bucket/file.txt"
+        );
+        let uri = minio
+            ._build_request_uri(Some("bucket".into()), None, None)
+            .unwrap();
+        assert_eq!(uri.host(), Some("[::1]"));
+        assert_eq!(uri.port_u16(), Some(9000));
+
+        let builder = Minio::builder().provider(StaticProvider::new("access", "secret", None));
+        assert!(builder.endpoint("[::1:9000").build().is_err());
+    }
+
+    #[test]
+    fn test_endpoint_scheme() {
+        let build = |endpoint: &str, secure: bool| {
+            Minio::builder()
+                .endpoint(endpoint)
+                .secure(secure)
+                .provider(StaticProvider::new("access", "secret", None))
+                .build()
+                .unwrap()
+                ._build_uri(Some("bucket".into()), None)
+        };
+        assert_eq!(
+            build("http://localhost:9000", true),
+            "http://localhost:9000/bucket"
+        );
+        assert_eq!(
+            build("http://localhost:9000", false),
+            "http://localhost:9000/bucket"
+        );
+        assert_eq!(
+            build("https://localhost:9000", true),
+            "https://localhost:9000/bucket"
+        );
+        assert_eq!(
+            build("https://localhost:9000", false),
+            "https://localhost:9000/bucket"
+        );
+        assert_eq!(
+            build("localhost:9000", true),
+            "https://localhost:9000/bucket"
+        );
+        assert_eq!(
+            build("localhost:9000", false),
+            "http://localhost:9000/bucket"
+        );
+    }
+
+    #[test]
+    fn test_part_size() {
+        let builder = || {
+            Minio::builder()
+                .endpoint("localhost:9000")
+                .provider(StaticProvider::new("access", "secret", None))
+        };
+        assert!(builder().part_size(1024 * 1024).build().is_err());
+        assert!(builder().part_size(6 * 1024 * 1024 * 1024).build().is_err());
+        assert!(builder().max_inflight_parts(0).build().is_err());
+        assert!(builder().min_part_size(0).part_size(0).build().is_err());
+        assert!(builder().max_object_size(0).build().is_err());
+
+        let minio = builder()
+            .min_part_size(1024 * 1024)
+            .part_size(1024 * 1024)
+            .max_object_size(1024 * 1024 * 1024)
+            .build()
+            .unwrap();
+        assert_eq!(minio.part_size(), 1024 * 1024);
+        assert_eq!(minio.max_object_size(), 1024 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_config() {
+        let config: MinioConfig = serde_json::from_str(
+            r#"{"endpoint": "localhost:9000", "secure": false, "path_prefix": "minio", "signature_version": "V2"}"#,
+        )
+        .unwrap();
+        assert_eq!(config.region, "us-east-1");
+        assert_eq!(config.part_size, 5 * 1024 * 1024);
+        assert_eq!(config.resume_retries, 3);
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(serde_json::from_str::<MinioConfig>(&json).unwrap(), config);
+
+        let minio = MinioBuilder::from_config(config)
+            .provider(StaticProvider::new("access", "secret", None))
+            .build()
+            .unwrap();
+        assert_eq!(
+            minio._build_uri(Some("bucket".into()), None),
+            "http://localhost:9000/minio/bucket"
+        );
+        assert_eq!(minio.inner.signature_version, SignatureVersion::V2);
+
+        let builder = MinioBuilder::from_config(MinioConfig::default())
+            .provider(StaticProvider::new("access", "secret", None));
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn test_virtual_hosted_style() {
+        let build = |virtual_hosted: bool| {
+            Minio::builder()
+                .endpoint("s3.amazonaws.com")
+                .virtual_hosted_style(virtual_hosted)
+                .provider(StaticProvider::new("access", "secret", None))
+                .build()
+                .unwrap()
+        };
+        let minio = build(true);
+        assert_eq!(
+            minio._build_uri(Some("bucket".into()), Some("file.txt".into())),
+            "https://bucket.s3.amazonaws.com/file.txt"
+        );
+        assert_eq!(
+            minio._build_uri(Some("my.bucket".into()), Some("file.txt".into())),
+            "https://s3.amazonaws.com/my.bucket/file.txt"
+        );
+
+        let minio = build(false);
+        assert_eq!(
+            minio._build_uri(Some("bucket".into()), Some("file.txt".into())),
+            "https://s3.amazonaws.com/bucket/file.txt"
+        );
+        assert_eq!(
+            minio._build_uri(Some("my.bucket".into()), Some("file.txt".into())),
+            "https://s3.amazonaws.com/my.bucket/file.txt"
+        );
+    }
 }
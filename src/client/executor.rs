@@ -1,13 +1,18 @@
+use std::time::Duration;
+
 use bytes::Bytes;
+use chrono::DateTime;
 use hyper::header::{HeaderName, HeaderValue};
-use hyper::{HeaderMap, Method};
-use reqwest::Response;
+use hyper::{header, HeaderMap, Method, StatusCode};
+use reqwest::{Body, Response};
 
 use super::{Minio, QueryMap};
 use crate::data::Data;
 use crate::datatype::{FromXml, ToXml};
-use crate::error::{Error, Result, S3Error};
-use crate::utils::md5sum_hash;
+use crate::error::{Error, Result, ValueError};
+use crate::signer::presign_v4;
+use crate::time::UtcTime;
+use crate::utils::{md5sum_hash, sha256sum_hash};
 
 /// An executor builds the S3 request.
 /// ```rust
@@ -41,7 +46,7 @@ use crate::utils::md5sum_hash;
 /// ```
 pub struct BaseExecutor<'a> {
     method: Method,
-    region: String,
+    region: Option<String>,
     bucket_name: Option<String>,
     object_name: Option<String>,
     body: Data<Error>,
@@ -55,7 +60,7 @@ impl<'a> BaseExecutor<'a> {
     pub fn new(method: Method, client: &'a Minio) -> Self {
         return Self {
             method,
-            region: client.region().to_string(),
+            region: None,
             bucket_name: None,
             object_name: None,
             body: Default::default(),
@@ -84,9 +89,11 @@ impl<'a> BaseExecutor<'a> {
         self
     }
 
-    /// Set the region.
+    /// Set the region, which is used in the credential scope of the signature.
+    ///
+    /// Default: the region of the bucket learned from a redirect, or the region of the client.
     pub fn region<T: Into<String>>(mut self, region: T) -> Self {
-        self.region = region.into();
+        self.region = Some(region.into());
         self
     }
 
@@ -108,9 +115,21 @@ impl<'a> BaseExecutor<'a> {
                 return self;
             }
         };
-        let body = Bytes::from(xml);
-        let md5 = md5sum_hash(&body);
-        self.body(body).header("Content-MD5", md5)
+        self.body_with_md5(Bytes::from(xml))
+    }
+
+    /// Set the request body and its `Content-MD5` header,
+    /// which is required by S3 for several sub-resources like tagging, retention and legal hold.
+    ///
+    /// The `x-amz-checksum-sha256` header is set instead if MD5 is not available.
+    pub fn body_with_md5(self, body: Bytes) -> Self {
+        match md5sum_hash(&body) {
+            Some(md5) => self.body(body).header("Content-MD5", md5),
+            None => {
+                let sha256 = sha256sum_hash(&body);
+                self.body(body).header("x-amz-checksum-sha256", sha256)
+            }
+        }
     }
 
     /// Set the new request header.
@@ -120,6 +139,9 @@ impl<'a> BaseExecutor<'a> {
     }
 
     /// Inserts a key-value pair into the request header.
+    ///
+    /// An invalid name or value is not inserted, the error is returned when the request is sent.
+    /// Use [try_header](Self::try_header) to get the error immediately.
     pub fn header<K, V>(mut self, key: K, value: V) -> Self
     where
         HeaderName: TryFrom<K>,
@@ -139,6 +161,35 @@ impl<'a> BaseExecutor<'a> {
         self
     }
 
+    /// Inserts a key-value pair into the request header,
+    /// returns the error if the name or value is invalid.
+    /// ## Example
+    /// ```rust
+    /// use hyper::Method;
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let executor = minio
+    ///     .executor(Method::GET)
+    ///     .bucket_name("bucket")
+    ///     .object_name("file.txt");
+    /// assert!(executor.try_header("x-amz-request-payer", "requester\n").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_header<K, V>(mut self, key: K, value: V) -> Result<Self>
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<crate::error::Error>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<crate::error::Error>,
+    {
+        let key = <HeaderName as TryFrom<K>>::try_from(key).map_err(Into::into)?;
+        let value = <HeaderValue as TryFrom<V>>::try_from(value).map_err(Into::into)?;
+        self.headers.insert(key, value);
+        Ok(self)
+    }
+
     /// Merge header into request header.
     #[inline]
     pub fn headers_merge(mut self, header: HeaderMap) -> Self {
@@ -187,16 +238,141 @@ impl<'a> BaseExecutor<'a> {
         apply(self)
     }
 
+    /// Get a presigned URL of the request with expiry time.
+    ///
+    /// - expires: Expiry time. between 1 second to 7 days.
+    ///
+    /// **Note**: Only the `host` header is signed, the headers and body of the executor are ignored.
+    /// ## Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use hyper::Method;
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let url: String = minio
+    ///     .executor(Method::PUT)
+    ///     .bucket_name("bucket")
+    ///     .object_name("file.txt")
+    ///     .query("uploadId", "upload_id")
+    ///     .query("partNumber", "1")
+    ///     .presign(Duration::from_secs(3600))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn presign(self, expires: Duration) -> Result<String> {
+        self.build_err?;
+        let expires = expires.as_secs() as usize;
+        if !(1..=604800).contains(&expires) {
+            return Err(ValueError::from("expires must be between 1 second to 7 days").into());
+        }
+        let mut querys = self.querys;
+        let credentials = self.client.fetch_credentials().await;
+        if let Some(token) = credentials.session_token() {
+            querys.insert("X-Amz-Security-Token".to_string(), token.to_string());
+        }
+        let query = querys.to_query_string();
+        let query = if query.is_empty() { None } else { Some(query) };
+        let region = self
+            .region
+            .unwrap_or_else(|| self.client._get_region(self.bucket_name.as_deref()));
+        let uri = self
+            .client
+            ._build_request_uri(self.bucket_name, self.object_name, query)?;
+        Ok(presign_v4(
+            &self.method,
+            &uri,
+            &region,
+            credentials.access_key(),
+            credentials.secret_key(),
+            &self.client.now(),
+            expires,
+        ))
+    }
+
+    /// Sign the request and return it without sending, for debugging or sending it by another HTTP client.
+    ///
+    /// The request is signed for the region of the bucket known by the client,
+    /// it is not sent again if S3 redirects it to another region.
+    /// ## Example
+    /// ```rust
+    /// use hyper::Method;
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let request = minio
+    ///     .executor(Method::GET)
+    ///     .bucket_name("bucket")
+    ///     .object_name("file.txt")
+    ///     .build_request()
+    ///     .await?;
+    /// let mut curl = format!("curl -X {} '{}'", request.method, request.uri);
+    /// for (name, value) in &request.headers {
+    ///     curl += &format!(" -H '{}: {}'", name, value.to_str().unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn build_request(self) -> Result<BuiltRequest> {
+        self.build_err?;
+        let region = self
+            .region
+            .unwrap_or_else(|| self.client._get_region(self.bucket_name.as_deref()));
+        let query = self.querys.to_query_string();
+        self.client
+            ._build_request(
+                self.method,
+                &region,
+                self.bucket_name,
+                self.object_name,
+                self.body,
+                Some(self.headers),
+                Some(query),
+            )
+            .await
+    }
+
     /// Send an HTTP request to S3 and return a Result<[Response]>.
     ///
     /// note: this is just a response from the s3 service, probably a wrong response.
+    ///
+    /// If the bucket resides in another region, S3 redirects the request with `301` or `307`
+    /// and the `x-amz-bucket-region` header, then the region of the bucket is remembered
+    /// and the request is sent again to that region, unless the body is a stream which cannot be sent twice.
     pub async fn send(self) -> Result<Response> {
+        self._send_redirect().await.map(|(res, _)| res)
+    }
+
+    /// send the request like [send](Self::send), return the response and the region it was sent to.
+    async fn _send_redirect(self) -> Result<(Response, String)> {
+        let (client, bucket_name, region) = (self.client, self.bucket_name.clone(), self._region());
+        let retry = self.try_clone();
+        let res = self._send(&region).await?;
+        match (redirect_region(&res, &region), bucket_name, retry) {
+            (Some(new_region), Some(bucket_name), Some(retry)) => {
+                client._set_region(&bucket_name, &new_region);
+                let res = retry._send(&new_region).await?;
+                Ok((res, new_region))
+            }
+            _ => Ok((res, region)),
+        }
+    }
+
+    /// return the region of the request.
+    fn _region(&self) -> String {
+        self.region
+            .clone()
+            .unwrap_or_else(|| self.client._get_region(self.bucket_name.as_deref()))
+    }
+
+    async fn _send(self, region: &str) -> Result<Response> {
         self.build_err?;
         let query = self.querys.to_query_string();
         self.client
             ._execute(
                 self.method,
-                &self.region,
+                region,
                 self.bucket_name,
                 self.object_name,
                 self.body,
@@ -209,15 +385,76 @@ impl<'a> BaseExecutor<'a> {
     /// Send an HTTP request to S3 and return a Result<[Response]>.
     ///
     /// This checks if the request is a legitimate S3 response.
+    ///
+    /// Same as [send](Self::send), the request redirected to another region is sent again,
+    /// the region is also read from the error of the response if the header is missing.
+    ///
+    /// If the request fails with `RequestTimeTooSkewed`, the offset of the server clock is
+    /// remembered, and the request is signed with the corrected time and sent again.
     pub async fn send_ok(self) -> Result<Response> {
-        let res = self.send().await?;
+        let (client, bucket_name) = (self.client, self.bucket_name.clone());
+        let retry = self.try_clone();
+        // the region may be changed by a redirect.
+        let (res, region) = self._send_redirect().await?;
         if res.status().is_success() {
-            Ok(res)
-        } else {
-            let text = res.text().await?;
-            let s: S3Error = text.as_str().try_into()?;
-            Err(s)?
+            return Ok(res);
+        }
+        let status = res.status();
+        let date = res.headers().get(header::DATE).cloned();
+        let err = client._read_error(res).await?;
+        if err.code == "RequestTimeTooSkewed" {
+            let server_time = err
+                .server_time
+                .as_deref()
+                .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+                .or_else(|| DateTime::parse_from_rfc2822(date?.to_str().ok()?).ok());
+            if let (Some(server_time), Some(retry)) = (server_time, retry) {
+                client._set_server_time(UtcTime::new(server_time.to_utc()));
+                let res = retry._send(&region).await?;
+                if res.status().is_success() {
+                    return Ok(res);
+                }
+                return Err(client._read_error(res).await?)?;
+            }
+            return Err(err)?;
+        }
+        let redirected = matches!(
+            status,
+            StatusCode::MOVED_PERMANENTLY | StatusCode::TEMPORARY_REDIRECT
+        );
+        let new_region = err.region.clone().filter(|r| redirected && *r != region);
+        if let (Some(new_region), Some(bucket_name), Some(retry)) = (new_region, bucket_name, retry)
+        {
+            client._set_region(&bucket_name, &new_region);
+            let res = retry._send(&new_region).await?;
+            if res.status().is_success() {
+                return Ok(res);
+            }
+            return Err(client._read_error(res).await?)?;
+        }
+        Err(err)?
+    }
+
+    /// Clone the executor if the body is in memory.
+    fn try_clone(&self) -> Option<Self> {
+        let body = match &self.body {
+            Data::Bytes(b) => Data::Bytes(b.clone()),
+            Data::Stream(..) => return None,
+        };
+        if self.build_err.is_err() {
+            return None;
         }
+        Some(Self {
+            method: self.method.clone(),
+            region: self.region.clone(),
+            bucket_name: self.bucket_name.clone(),
+            object_name: self.object_name.clone(),
+            body,
+            headers: self.headers.clone(),
+            querys: self.querys.clone(),
+            client: self.client,
+            build_err: Ok(()),
+        })
     }
 
     /// Send an HTTP request to S3 and return a Result<[String]>.
@@ -242,3 +479,31 @@ impl<'a> BaseExecutor<'a> {
             .map_err(Into::into)
     }
 }
+
+/// A signed request built by [BaseExecutor::build_request], which is not sent.
+///
+/// The request can be inspected, or sent by another HTTP client.
+#[derive(Debug)]
+pub struct BuiltRequest {
+    pub method: Method,
+    /// The full URI with the query string.
+    pub uri: String,
+    /// The signed headers, including `Authorization`.
+    pub headers: HeaderMap,
+    pub body: Body,
+}
+
+/// return the region in the header if the response is a redirect to another region.
+fn redirect_region(res: &Response, region: &str) -> Option<String> {
+    if !matches!(
+        res.status(),
+        StatusCode::MOVED_PERMANENTLY | StatusCode::TEMPORARY_REDIRECT
+    ) {
+        return None;
+    }
+    res.headers()
+        .get("x-amz-bucket-region")
+        .and_then(|x| x.to_str().ok())
+        .filter(|r| !r.is_empty() && *r != region)
+        .map(String::from)
+}
@@ -13,13 +13,20 @@ mod querymap;
 mod response;
 mod select_object_reader;
 
+#[cfg(feature = "compress")]
+pub use args::Compression;
 pub use args::{
     BucketArgs, CopySource, KeyArgs, ListMultipartUploadsArgs, ListObjectVersionsArgs,
-    ListObjectsArgs, MultipartUploadTask, ObjectLockConfig, PresignedArgs, Tags,
+    ListObjectsArgs, MetadataDirective, MultipartUploadTask, ObjectLockConfig, PostPolicy,
+    PresignedArgs, TaggingDirective, Tags, UploadSource,
 };
 pub use bucket::Bucket;
 pub use client::*;
-pub use executor::BaseExecutor;
+pub use executor::{BaseExecutor, BuiltRequest};
+pub use mutilpart_upload::{MultipartUploadGuard, MultipartUploadHandle};
 pub use querymap::QueryMap;
-pub use response::ObjectStat;
+#[cfg(feature = "ext")]
+pub use response::BulkResult;
+pub use response::{GetObjectResponse, ObjectDescription, ObjectStat};
+pub use response::{PutObjectResult, RemoveObjectResult};
 pub use select_object_reader::{Message, SelectObjectReader};
@@ -1,14 +1,19 @@
-use bytes::Bytes;
+use std::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+use chrono::{DateTime, Utc};
+use hyper::header::HeaderValue;
 use hyper::{header, HeaderMap, Method};
+use reqwest::Response;
 
 use super::args::MultipartUploadTask;
-use super::{BucketArgs, CopySource, KeyArgs, ListMultipartUploadsArgs};
-use crate::datatype::Part;
+use super::{BucketArgs, CopySource, KeyArgs, ListMultipartUploadsArgs, PutObjectResult};
 use crate::datatype::{
     CompleteMultipartUpload, CompleteMultipartUploadResult, CopyPartResult,
     InitiateMultipartUploadResult, ListMultipartUploadsResult, ListPartsResult,
 };
-use crate::error::{Result, S3Error, ValueError};
+use crate::datatype::{FromXml, Part};
+use crate::error::{Result, ValueError};
 use crate::signer::{MAX_MULTIPART_COUNT, MAX_PART_SIZE};
 use crate::Minio;
 
@@ -33,19 +38,35 @@ impl Minio {
         if res.status() == 204 {
             Ok(())
         } else {
-            let text = res.text().await?;
-            let s: S3Error = text.as_str().try_into()?;
-            Err(s)?
+            Err(self._read_error(res).await?)?
         }
     }
 
     /// Completes a multipart upload by assembling previously uploaded parts.
+    ///
+    /// The parts are sorted by part number before sending, and they must be numbered
+    /// consecutively from 1. Repeated parts with the same ETag are ignored,
+    /// a [ValueError] is returned if the parts are empty, have gaps or conflicting duplicates.
     pub async fn complete_multipart_upload(
         &self,
         task: &MultipartUploadTask,
         parts: Vec<Part>,
         extra_header: Option<HeaderMap>,
     ) -> Result<CompleteMultipartUploadResult> {
+        let res = self
+            ._complete_multipart_upload(task, parts, extra_header)
+            .await?;
+        CompleteMultipartUploadResult::from_xml(res.text().await?)
+    }
+
+    /// Completes a multipart upload and returns the raw [Response].
+    pub(crate) async fn _complete_multipart_upload(
+        &self,
+        task: &MultipartUploadTask,
+        parts: Vec<Part>,
+        extra_header: Option<HeaderMap>,
+    ) -> Result<Response> {
+        let parts = sort_parts(parts)?;
         let body = CompleteMultipartUpload { parts };
         self.executor(Method::POST)
             .bucket_name(task.bucket())
@@ -61,7 +82,7 @@ impl Minio {
             .headers_merge2(extra_header)
             .headers_merge2(task.ssec_header().cloned())
             .xml(&body)
-            .send_xml_ok()
+            .send_ok()
             .await
     }
 
@@ -90,6 +111,7 @@ impl Minio {
             )
             .headers_merge(metadata_header)
             .headers_merge2(key.extra_headers)
+            .querys_merge(key.extra_querys)
             .headers_merge2(key.ssec_headers.clone())
             .send_xml_ok::<InitiateMultipartUploadResult>()
             .await
@@ -99,6 +121,47 @@ impl Minio {
         Ok(result)
     }
 
+    /// Begin a multipart upload whose content is written incrementally through the returned
+    /// [MultipartUploadHandle], the caller controls when the upload is finished or aborted.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// use bytes::Bytes;
+    ///
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let mut upload = minio.begin_multipart("bucket", "events.log").await?;
+    /// for event in ["start\n", "stop\n"] {
+    ///     if let Err(e) = upload.write(Bytes::from(event)).await {
+    ///         upload.abort().await?;
+    ///         return Err(e);
+    ///     }
+    /// }
+    /// let result = upload.finish().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn begin_multipart<B, K>(&self, bucket: B, key: K) -> Result<MultipartUploadHandle>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        let key: KeyArgs = key.into();
+        let complete_header = key.if_none_match.then(|| {
+            let mut header = HeaderMap::new();
+            header.insert(header::IF_NONE_MATCH, HeaderValue::from_static("*"));
+            header
+        });
+        let task = self.create_multipart_upload(bucket, key).await?;
+        Ok(MultipartUploadHandle {
+            minio: self.clone(),
+            guard: MultipartUploadGuard::new(self, task),
+            complete_header,
+            buffer: BytesMut::new(),
+            parts: Vec::new(),
+        })
+    }
+
     /// lists in-progress multipart uploads.
     pub async fn list_multipart_uploads(
         &self,
@@ -112,6 +175,71 @@ impl Minio {
             .await
     }
 
+    /// Aborts the in-progress multipart uploads of a bucket, returns the count aborted.
+    ///
+    /// - older_than: only abort the uploads initiated before this duration ago, abort all if [None].
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// use std::time::Duration;
+    /// # async fn example(minio: Minio){
+    /// let day = Duration::from_secs(24 * 3600);
+    /// let count = minio.abort_incomplete_multipart_uploads("bucket", Some(day)).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn abort_incomplete_multipart_uploads<B>(
+        &self,
+        bucket: B,
+        older_than: Option<Duration>,
+    ) -> Result<usize>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let before = match older_than {
+            Some(d) => {
+                let d =
+                    chrono::Duration::from_std(d).map_err(|e| ValueError::new(e.to_string()))?;
+                Some(self.now().datetime() - d)
+            }
+            None => None,
+        };
+        let mut args = ListMultipartUploadsArgs::new(bucket.name.clone());
+        if let Some(owner) = &bucket.expected_bucket_owner {
+            args = args.expected_bucket_owner(owner);
+        }
+        let mut count = 0;
+        loop {
+            let res = self.list_multipart_uploads(args.clone()).await?;
+            for upload in res.uploads {
+                if let Some(before) = before {
+                    // skip the uploads whose initiated time is unknown.
+                    match upload.initiated.parse::<DateTime<Utc>>() {
+                        Ok(initiated) if initiated < before => {}
+                        _ => continue,
+                    }
+                }
+                let task = MultipartUploadTask::new(
+                    bucket.name.clone(),
+                    upload.key,
+                    upload.upload_id,
+                    bucket.expected_bucket_owner.clone(),
+                    None,
+                    None,
+                );
+                self.abort_multipart_upload(&task).await?;
+                count += 1;
+            }
+            if !res.is_truncated {
+                break;
+            }
+            args = args
+                .key_marker(res.next_key_marker)
+                .upload_id_marker(res.next_upload_id_marker);
+        }
+        Ok(count)
+    }
+
     /// Lists the parts that have been uploaded for a specific multipart upload.
     pub async fn list_parts(
         &self,
@@ -187,9 +315,7 @@ impl Minio {
                 Err(res.into())
             }
         } else {
-            let text = res.text().await?;
-            let s: S3Error = text.as_str().try_into()?;
-            Err(s)?
+            Err(self._read_error(res).await?)?
         }
     }
 
@@ -224,3 +350,309 @@ impl Minio {
             .map(|CopyPartResult { e_tag }| Part { e_tag, part_number })
     }
 }
+
+/// A multipart upload in progress, created by [Minio::begin_multipart].
+///
+/// The written data is buffered and uploaded in parts of the part size of the client.
+/// The upload should be ended by [finish](Self::finish) or [abort](Self::abort),
+/// if the handle is dropped before, the upload is aborted by its [MultipartUploadGuard].
+///
+/// If [write](Self::write) or [flush](Self::flush) fails to upload a part, its data is kept
+/// in the buffer, so the upload can be retried by [flush](Self::flush), finished or aborted.
+/// [finish](Self::finish) consumes the handle, if it fails the upload is aborted by the guard.
+pub struct MultipartUploadHandle {
+    minio: Minio,
+    guard: MultipartUploadGuard,
+    complete_header: Option<HeaderMap>,
+    buffer: BytesMut,
+    parts: Vec<Part>,
+}
+
+impl MultipartUploadHandle {
+    /// The [MultipartUploadTask] of the upload.
+    pub fn task(&self) -> &MultipartUploadTask {
+        self.guard.task()
+    }
+
+    /// Byte length of the data buffered, which is not uploaded yet.
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Parts uploaded so far.
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+
+    /// Write data to the upload, each time the buffered data reaches the part size, a part is uploaded.
+    pub async fn write(&mut self, data: Bytes) -> Result<()> {
+        self.buffer.extend_from_slice(&data);
+        let part_size = self.minio.part_size();
+        while self.buffer.len() >= part_size {
+            self.upload(part_size).await?;
+        }
+        Ok(())
+    }
+
+    /// Upload the buffered data as a part, without waiting for the part size.
+    ///
+    /// S3 requires all parts but the last one to be at least the minimum part size,
+    /// so the data is kept in the buffer if it is smaller.
+    pub async fn flush(&mut self) -> Result<()> {
+        if !self.buffer.is_empty() && self.buffer.len() >= self.minio.min_part_size() {
+            self.upload(self.buffer.len()).await?;
+        }
+        Ok(())
+    }
+
+    /// Upload the buffered data as the last part, then complete the upload.
+    ///
+    /// If the last part or the completion fails, the handle is dropped and the upload is aborted.
+    pub async fn finish(mut self) -> Result<PutObjectResult> {
+        if !self.buffer.is_empty() || self.parts.is_empty() {
+            self.upload(self.buffer.len()).await?;
+        }
+        let res = self
+            .minio
+            ._complete_multipart_upload(self.guard.task(), self.parts, self.complete_header)
+            .await?;
+        self.guard.disarm();
+        let task = self.guard.task();
+        PutObjectResult::from_complete_response(task.bucket().into(), task.key().into(), res).await
+    }
+
+    /// Abort the upload, the buffered data is dropped.
+    pub async fn abort(mut self) -> Result<()> {
+        self.guard.disarm();
+        self.minio.abort_multipart_upload(self.guard.task()).await
+    }
+
+    /// Upload the first `len` bytes of the buffer as the next part,
+    /// they are removed from the buffer only if the part is uploaded.
+    async fn upload(&mut self, len: usize) -> Result<()> {
+        let part_number = self.parts.len() + 1;
+        let body = self.buffer.split_to(len).freeze();
+        match self
+            .minio
+            .upload_part(self.guard.task(), part_number, body.clone())
+            .await
+        {
+            Ok(part) => {
+                self.parts.push(part);
+                Ok(())
+            }
+            Err(e) => {
+                let mut buffer = BytesMut::from(body.as_ref());
+                buffer.extend_from_slice(&self.buffer);
+                self.buffer = buffer;
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Aborts a multipart upload when dropped, unless it is [disarmed](Self::disarm)
+/// after the upload is completed or aborted, so that a cancelled upload does not leak its parts.
+///
+/// There is no async drop, so the abort is best-effort: with the `fs-tokio` feature it is spawned
+/// on the current tokio runtime and its result is ignored. Without a tokio runtime the upload is
+/// kept, a warning with the upload ID is logged with the `tracing` feature, and
+/// [abort_incomplete_multipart_uploads](Minio::abort_incomplete_multipart_uploads) can clean it up later.
+/// ## Example
+/// ```rust
+/// # use sc_minio::Minio;
+/// # use sc_minio::error::Result;
+/// use bytes::Bytes;
+/// use sc_minio::client::MultipartUploadGuard;
+///
+/// # async fn example(minio: Minio)->Result<()>{
+/// let task = minio.create_multipart_upload("bucket", "file.txt").await?;
+/// let mut guard = MultipartUploadGuard::new(&minio, task);
+/// let part = minio.upload_part(guard.task(), 1, Bytes::from("hello")).await?;
+/// minio.complete_multipart_upload(guard.task(), vec![part], None).await?;
+/// guard.disarm();
+/// # Ok(())
+/// # }
+/// ```
+pub struct MultipartUploadGuard {
+    #[cfg_attr(not(feature = "fs-tokio"), allow(dead_code))]
+    minio: Minio,
+    task: MultipartUploadTask,
+    armed: bool,
+}
+
+impl MultipartUploadGuard {
+    pub fn new(minio: &Minio, task: MultipartUploadTask) -> Self {
+        Self {
+            minio: minio.clone(),
+            task,
+            armed: true,
+        }
+    }
+
+    /// The [MultipartUploadTask] guarded.
+    pub fn task(&self) -> &MultipartUploadTask {
+        &self.task
+    }
+
+    /// Keep the upload when dropped, call it after the upload is completed or aborted.
+    pub fn disarm(&mut self) {
+        self.armed = false;
+    }
+}
+
+impl MultipartUploadGuard {
+    /// spawn the abort on the current tokio runtime, return false if there is no runtime.
+    fn spawn_abort(&self) -> bool {
+        #[cfg(feature = "fs-tokio")]
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let (minio, task) = (self.minio.clone(), self.task.clone());
+            handle.spawn(async move { minio.abort_multipart_upload(&task).await });
+            return true;
+        }
+        false
+    }
+}
+
+impl Drop for MultipartUploadGuard {
+    fn drop(&mut self) {
+        if self.armed && !self.spawn_abort() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                bucket = self.task.bucket(),
+                key = self.task.key(),
+                upload_id = self.task.upload_id(),
+                "multipart upload dropped without completion"
+            );
+        }
+    }
+}
+
+impl Minio {
+    /// compute the part size to upload an object of `size` bytes in at most [MAX_MULTIPART_COUNT] parts.
+    ///
+    /// The part size of the client is used if it is large enough, otherwise the size is rounded up
+    /// to a multiple of the min part size. Objects larger than the max object size are rejected.
+    pub(crate) fn optimal_part_size(&self, size: usize) -> std::result::Result<usize, ValueError> {
+        let (part_size, min_part_size) = (self.part_size(), self.min_part_size());
+        let max_object_size = self.max_object_size();
+        if size > max_object_size {
+            return Err(ValueError::new(format!(
+                "object size {size} is larger than the maximum {max_object_size} bytes."
+            )));
+        }
+        if size.div_ceil(part_size) <= MAX_MULTIPART_COUNT {
+            return Ok(part_size);
+        }
+        let part_size = size
+            .div_ceil(MAX_MULTIPART_COUNT)
+            .div_ceil(min_part_size)
+            .saturating_mul(min_part_size);
+        if part_size > MAX_PART_SIZE {
+            return Err(ValueError::new(format!(
+                "part size {part_size} is larger than the maximum {MAX_PART_SIZE} bytes."
+            )));
+        }
+        Ok(part_size)
+    }
+}
+
+/// sort parts by part number, drop repeated parts and validate there are no gaps.
+fn sort_parts(mut parts: Vec<Part>) -> std::result::Result<Vec<Part>, ValueError> {
+    if parts.is_empty() {
+        return Err(ValueError::from("parts cannot be empty."));
+    }
+    parts.sort_by_key(|p| p.part_number);
+    parts.dedup_by(|a, b| a.part_number == b.part_number && a.e_tag == b.e_tag);
+    for (i, part) in parts.iter().enumerate() {
+        if part.part_number < i + 1 {
+            return Err(ValueError::new(format!(
+                "duplicate part number {} with different ETag.",
+                part.part_number
+            )));
+        }
+        if part.part_number > i + 1 {
+            return Err(ValueError::new(format!("missing part number {}.", i + 1)));
+        }
+    }
+    Ok(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sort_parts;
+    use crate::datatype::Part;
+    use crate::provider::StaticProvider;
+    use crate::signer::{MAX_MULTIPART_COUNT, MAX_MULTIPART_OBJECT_SIZE, MIN_PART_SIZE};
+    use crate::Minio;
+
+    fn part(part_number: usize, e_tag: &str) -> Part {
+        Part {
+            e_tag: e_tag.to_string(),
+            part_number,
+        }
+    }
+
+    #[test]
+    fn test_sort_parts() {
+        let parts = sort_parts(vec![part(3, "c"), part(1, "a"), part(2, "b")]).unwrap();
+        let numbers: Vec<usize> = parts.iter().map(|p| p.part_number).collect();
+        assert_eq!(numbers, vec![1, 2, 3]);
+        assert_eq!(parts[2].e_tag, "c");
+
+        let parts = sort_parts(vec![part(2, "b"), part(1, "a"), part(2, "b")]).unwrap();
+        assert_eq!(parts.len(), 2);
+
+        assert!(sort_parts(vec![]).is_err());
+        assert!(sort_parts(vec![part(1, "a"), part(2, "b"), part(2, "x")]).is_err());
+        assert!(sort_parts(vec![part(1, "a"), part(3, "c")]).is_err());
+        assert!(sort_parts(vec![part(2, "b"), part(3, "c")]).is_err());
+    }
+
+    #[test]
+    fn test_optimal_part_size() {
+        const GIB: usize = 1024 * 1024 * 1024;
+        let builder = || {
+            Minio::builder()
+                .endpoint("localhost:9000")
+                .provider(StaticProvider::new("access", "secret", None))
+        };
+        let minio = builder().build().unwrap();
+        assert_eq!(minio.optimal_part_size(GIB).unwrap(), MIN_PART_SIZE);
+        assert_eq!(minio.optimal_part_size(0).unwrap(), MIN_PART_SIZE);
+
+        for size in [100 * GIB, MAX_MULTIPART_OBJECT_SIZE] {
+            let part_size = minio.optimal_part_size(size).unwrap();
+            assert_eq!(part_size % MIN_PART_SIZE, 0);
+            assert!(size.div_ceil(part_size) <= MAX_MULTIPART_COUNT);
+        }
+        assert_eq!(
+            minio.optimal_part_size(100 * GIB).unwrap(),
+            3 * MIN_PART_SIZE
+        );
+        assert!(minio
+            .optimal_part_size(MAX_MULTIPART_OBJECT_SIZE + 1)
+            .is_err());
+
+        // the limits of a backend with larger parts and objects.
+        let min_part_size = 16 * 1024 * 1024;
+        let minio = builder()
+            .min_part_size(min_part_size)
+            .part_size(min_part_size)
+            .max_object_size(2 * MAX_MULTIPART_OBJECT_SIZE)
+            .build()
+            .unwrap();
+        assert_eq!(minio.optimal_part_size(GIB).unwrap(), min_part_size);
+        assert_eq!(
+            minio.optimal_part_size(500 * GIB).unwrap(),
+            4 * min_part_size
+        );
+        let size = MAX_MULTIPART_OBJECT_SIZE + 1;
+        let part_size = minio.optimal_part_size(size).unwrap();
+        assert_eq!(part_size % min_part_size, 0);
+        assert!(size.div_ceil(part_size) <= MAX_MULTIPART_COUNT);
+        assert!(minio
+            .optimal_part_size(2 * MAX_MULTIPART_OBJECT_SIZE + 1)
+            .is_err());
+    }
+}
@@ -3,15 +3,23 @@ use hyper::Method;
 
 use super::args::ObjectLockConfig;
 use super::{BucketArgs, ListObjectVersionsArgs, ListObjectsArgs, Tags};
+use crate::datatype::AccelerateConfiguration;
 use crate::datatype::AccessControlPolicy;
+use crate::datatype::BucketLoggingStatus;
+use crate::datatype::BucketPolicy;
 use crate::datatype::CORSConfiguration;
+use crate::datatype::InventoryConfiguration;
 use crate::datatype::ListAllMyBucketsResult;
 use crate::datatype::ListBucketResult;
+use crate::datatype::ListInventoryConfigurationsResult;
 use crate::datatype::ListVersionsResult;
 use crate::datatype::LocationConstraint;
+use crate::datatype::OwnershipControls;
 use crate::datatype::PublicAccessBlockConfiguration;
+use crate::datatype::RequestPaymentConfiguration;
 use crate::datatype::ServerSideEncryptionConfiguration;
-use crate::datatype::{Bucket, Owner, VersioningConfiguration};
+use crate::datatype::WebsiteConfiguration;
+use crate::datatype::{Bucket, Owner, VersioningConfiguration, VersioningStatus};
 use crate::error::{Error, Result};
 use crate::Minio;
 
@@ -123,6 +131,47 @@ impl Minio {
             .map(|res| res.status().is_success())
     }
 
+    /// Check if a bucket exists and get the region it resides in.
+    ///
+    /// The region is read from the `x-amz-bucket-region` header of a `HEAD` request,
+    /// falling back to [Minio::get_bucket_region] if the server doesn't return it.
+    ///
+    /// If bucket exists and you have permission to access it, return [Ok(Some(region))], otherwise [Ok(None)].
+    /// The region is also returned if S3 answers `301` or `403` with the header,
+    /// which means the bucket exists in that region, though it may not be accessible.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// # async fn example(minio: Minio) -> Result<()>{
+    /// if let Some(region) = minio.head_bucket("bucket").await? {
+    ///     println!("bucket resides in {region}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn head_bucket<B>(&self, bucket: B) -> Result<Option<String>>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let res = self
+            ._bucket_executor(bucket.clone(), Method::HEAD)
+            .send()
+            .await?;
+        let region = res
+            .headers()
+            .get("x-amz-bucket-region")
+            .and_then(|x| x.to_str().ok())
+            .filter(|x| !x.is_empty())
+            .map(ToOwned::to_owned);
+        match region {
+            Some(region) => Ok(Some(region)),
+            None if !res.status().is_success() => Ok(None),
+            None => self.get_bucket_region(bucket).await.map(Some),
+        }
+    }
+
     /// List information of all accessible buckets.
     /// ## Example
     /// ```rust
@@ -139,6 +188,56 @@ impl Minio {
         Ok((res.buckets.bucket, res.owner))
     }
 
+    /// Check that the S3 service is reachable and the credentials are valid,
+    /// by an authenticated `ListBuckets` request for at most one bucket.
+    ///
+    /// Used in readiness probes, wrap it with a timeout to bound the wait.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// # async fn example(minio: Minio){
+    /// minio.ping().await.expect("S3 service is not available");
+    /// # }
+    /// ```
+    pub async fn ping(&self) -> Result<()> {
+        self.executor(Method::GET)
+            .query("max-buckets", "1")
+            .send_ok()
+            .await
+            .map(|_| ())
+    }
+
+    /// List information of all accessible buckets in the region.
+    ///
+    /// The buckets are filtered on the client side, the region of a bucket is queried
+    /// by [get_bucket_region](Self::get_bucket_region) if the S3 service does not return it.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// # async fn example(minio: Minio){
+    /// let buckets = minio.list_buckets_by_region("us-east-1").await.unwrap();
+    /// # }
+    /// ```
+    pub async fn list_buckets_by_region(&self, region: &str) -> Result<Vec<Bucket>> {
+        let (buckets, _) = self.list_buckets().await?;
+        let mut result = Vec::new();
+        for mut bucket in buckets {
+            if bucket.bucket_region.is_none() {
+                let bucket_region = self.get_bucket_region(bucket.name.as_str()).await?;
+                // an empty location constraint means `us-east-1`.
+                bucket.bucket_region = Some(if bucket_region.is_empty() {
+                    "us-east-1".to_string()
+                } else {
+                    bucket_region
+                });
+            }
+            if bucket.bucket_region.as_deref() == Some(region) {
+                result.push(bucket);
+            }
+        }
+        Ok(result)
+    }
+
     /// Lists metadata about all versions of the objects in a bucket.
     /// ## Example
     /// ```rust
@@ -231,14 +330,16 @@ impl Minio {
         self.executor(Method::PUT)
             .bucket_name(bucket.name)
             .headers_merge2(bucket.extra_headers)
-            .apply(|e| {
+            .apply(|mut e| {
                 if object_lock {
-                    e.header("x-amz-bucket-object-lock-enabled", "true")
-                } else {
-                    e
+                    e = e.header("x-amz-bucket-object-lock-enabled", "true")
+                }
+                if let Some(owner) = bucket.expected_bucket_owner {
+                    e = e.header("x-amz-expected-bucket-owner", owner)
                 }
+                e
             })
-            .body(body)
+            .body_with_md5(body.into())
             .send_ok()
             .await
             .map(|res| {
@@ -252,6 +353,38 @@ impl Minio {
             })?
     }
 
+    /// Create a bucket with object_lock if it does not exist.
+    ///
+    /// Same as [Minio::make_bucket], but `BucketAlreadyOwnedByYou` and `BucketAlreadyExists`
+    /// errors are treated as success.
+    ///
+    /// Return [Ok(Some(location))] if the bucket was created, [Ok(None)] if it already existed.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// # async fn example(minio: Minio){
+    /// minio.make_bucket_if_not_exists("bucket", false).await;
+    /// # }
+    /// ```
+    pub async fn make_bucket_if_not_exists<B>(
+        &self,
+        bucket: B,
+        object_lock: bool,
+    ) -> Result<Option<String>>
+    where
+        B: Into<BucketArgs>,
+    {
+        match self.make_bucket(bucket, object_lock).await {
+            Ok(location) => Ok(Some(location)),
+            Err(Error::S3Error(s))
+                if s.code == "BucketAlreadyOwnedByYou" || s.code == "BucketAlreadyExists" =>
+            {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     /// Remove an **empty** bucket.
     /// If the operation succeeds, return [Ok] otherwise [Error]
     /// ## Example
@@ -290,6 +423,195 @@ impl Minio {
     set_attr!(set_public_access_block, "publicAccessBlock", PublicAccessBlockConfiguration);
     del_attr!(del_public_access_block, "publicAccessBlock");
 
+    #[rustfmt::skip]
+    get_attr!(get_bucket_ownership_controls, "ownershipControls", OwnershipControls);
+    #[rustfmt::skip]
+    set_attr!(set_bucket_ownership_controls, "ownershipControls", OwnershipControls);
+
+    #[rustfmt::skip]
+    get_attr!(get_bucket_accelerate_configuration, "accelerate", AccelerateConfiguration);
+    #[rustfmt::skip]
+    set_attr!(set_bucket_accelerate_configuration, "accelerate", AccelerateConfiguration);
+
+    #[rustfmt::skip]
+    get_attr!(get_bucket_request_payment, "requestPayment", RequestPaymentConfiguration);
+    #[rustfmt::skip]
+    set_attr!(set_bucket_request_payment, "requestPayment", RequestPaymentConfiguration);
+
+    #[rustfmt::skip]
+    get_attr!(get_bucket_logging, "logging", BucketLoggingStatus);
+    #[rustfmt::skip]
+    set_attr!(set_bucket_logging, "logging", BucketLoggingStatus);
+
+    /// Get [InventoryConfiguration] of a bucket by the inventory id.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let config = minio.get_bucket_inventory_configuration("bucket", "report").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn get_bucket_inventory_configuration<B, T>(
+        &self,
+        bucket: B,
+        id: T,
+    ) -> Result<InventoryConfiguration>
+    where
+        B: Into<BucketArgs>,
+        T: Into<String>,
+    {
+        self._bucket_executor(bucket.into(), Method::GET)
+            .query("inventory", "")
+            .query("id", id)
+            .send_xml_ok()
+            .await
+    }
+
+    /// Set [InventoryConfiguration] of a bucket, identified by [InventoryConfiguration::id].
+    /// ## Example
+    /// ```rust
+    /// use sc_minio::datatype::*;
+    /// # use sc_minio::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let config = InventoryConfiguration {
+    ///     destination: InventoryDestination {
+    ///         s3_bucket_destination: InventoryS3BucketDestination {
+    ///             account_id: None,
+    ///             bucket: "arn:aws:s3:::reports".to_string(),
+    ///             format: InventoryFormat::CSV,
+    ///             prefix: None,
+    ///         },
+    ///     },
+    ///     filter: None,
+    ///     id: "report".to_string(),
+    ///     included_object_versions: InventoryIncludedObjectVersions::Current,
+    ///     is_enabled: true,
+    ///     optional_fields: None,
+    ///     schedule: InventorySchedule {
+    ///         frequency: InventoryFrequency::Daily,
+    ///     },
+    /// };
+    /// minio.put_bucket_inventory_configuration("bucket", config).await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn put_bucket_inventory_configuration<B>(
+        &self,
+        bucket: B,
+        value: InventoryConfiguration,
+    ) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        self._bucket_executor(bucket.into(), Method::PUT)
+            .query("inventory", "")
+            .query("id", value.id.as_str())
+            .xml(&value)
+            .send_ok()
+            .await
+            .map(|_| ())
+    }
+
+    /// Delete [InventoryConfiguration] of a bucket by the inventory id.
+    pub async fn delete_bucket_inventory_configuration<B, T>(&self, bucket: B, id: T) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+        T: Into<String>,
+    {
+        self._bucket_executor(bucket.into(), Method::DELETE)
+            .query("inventory", "")
+            .query("id", id)
+            .send_ok()
+            .await
+            .map(|_| ())
+    }
+
+    /// List all [InventoryConfiguration] of a bucket.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let configs = minio.list_bucket_inventory_configurations("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn list_bucket_inventory_configurations<B>(
+        &self,
+        bucket: B,
+    ) -> Result<Vec<InventoryConfiguration>>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let mut configs = vec![];
+        let mut token = None;
+        loop {
+            let res: ListInventoryConfigurationsResult = self
+                ._bucket_executor(bucket.clone(), Method::GET)
+                .query("inventory", "")
+                .apply(|e| match token.take() {
+                    Some(token) => e.query("continuation-token", token),
+                    None => e,
+                })
+                .send_xml_ok()
+                .await?;
+            configs.extend(res.inventory_configurations);
+            match res.next_continuation_token {
+                Some(next) if res.is_truncated && !next.is_empty() => token = Some(next),
+                _ => return Ok(configs),
+            }
+        }
+    }
+
+    /// Get [Option]<[BucketPolicy]> of a bucket.
+    /// Note: return [None] if bucket had not set policy or delete policy.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let policy = minio.get_bucket_policy("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn get_bucket_policy<B>(&self, bucket: B) -> Result<Option<BucketPolicy>>
+    where
+        B: Into<BucketArgs>,
+    {
+        let res = self
+            ._bucket_executor(bucket.into(), Method::GET)
+            .query("policy", "")
+            .send_text_ok()
+            .await;
+        match res {
+            Ok(text) => BucketPolicy::from_json(&text).map(Some),
+            Err(Error::S3Error(s)) if s.code == "NoSuchBucketPolicy" => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Set [BucketPolicy] of a bucket.
+    /// ## Example
+    /// ```rust
+    /// use sc_minio::datatype::PolicyBuilder;
+    /// # use sc_minio::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let policy = PolicyBuilder::public_read("bucket").build();
+    /// minio.set_bucket_policy("bucket", policy).await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn set_bucket_policy<B>(&self, bucket: B, policy: BucketPolicy) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let policy = policy.to_json()?;
+        self._bucket_executor(bucket.into(), Method::PUT)
+            .query("policy", "")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body_with_md5(policy.into())
+            .send_ok()
+            .await
+            .map(|_| ())
+    }
+
+    del_attr!(del_bucket_policy, "policy");
+
     /// Get [Option]<[Tags]> of a bucket.
     /// Note: return [None] if bucket had not set tagging or delete tagging.
     /// ## Example
@@ -319,12 +641,74 @@ impl Minio {
         }
     }
 
-    set_attr!(set_bucket_tags, "tagging", Tags);
+    /// Set [Tags] of a bucket.
+    ///
+    /// A bucket can have at most 50 tags, a [ValueError](crate::error::ValueError) is returned if the tags exceed the limits.
+    pub async fn set_bucket_tags<B>(&self, bucket: B, value: Tags) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        value.validate(50)?;
+        self._bucket_executor(bucket.into(), Method::PUT)
+            .query("tagging", "")
+            .xml(&value)
+            .send_ok()
+            .await
+            .map(|_| ())
+    }
     del_attr!(del_bucket_tags, "tagging");
 
     get_attr!(get_bucket_versioning, "versioning", VersioningConfiguration);
     set_attr!(set_bucket_versioning, "versioning", VersioningConfiguration);
 
+    /// Returns true if versioning is enabled on a bucket,
+    /// false if it is suspended or has never been enabled.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// if minio.is_bucket_versioning_enabled("bucket").await? {
+    ///     println!("versioning is enabled");
+    /// }
+    /// # Ok(())}
+    /// ```
+    pub async fn is_bucket_versioning_enabled<B>(&self, bucket: B) -> Result<bool>
+    where
+        B: Into<BucketArgs>,
+    {
+        self.get_bucket_versioning(bucket)
+            .await
+            .map(|config| config.status == Some(VersioningStatus::Enabled))
+    }
+
+    /// Get [Option]<[WebsiteConfiguration]> of a bucket.
+    /// Note: return [None] if bucket had not set website configuration or delete it.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let config = minio.get_bucket_website("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn get_bucket_website<B>(&self, bucket: B) -> Result<Option<WebsiteConfiguration>>
+    where
+        B: Into<BucketArgs>,
+    {
+        let res = self
+            ._bucket_executor(bucket.into(), Method::GET)
+            .query("website", "")
+            .send_xml_ok::<WebsiteConfiguration>()
+            .await;
+        match res {
+            Ok(config) => Ok(Some(config)),
+            Err(Error::S3Error(s)) if s.code == "NoSuchWebsiteConfiguration" => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    set_attr!(set_bucket_website, "website", WebsiteConfiguration);
+    del_attr!(del_bucket_website, "website");
+
     get_attr!(get_object_lock_config, "object-lock", ObjectLockConfig);
     set_attr!(set_object_lock_config, "object-lock", ObjectLockConfig);
 
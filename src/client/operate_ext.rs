@@ -1,12 +1,20 @@
 use core::str;
 use std::pin::Pin;
+use std::time::Duration;
 
-use crate::{datatype::Object, error::Result, Minio};
+use crate::{
+    datatype::{DeleteError, NotificationInfo, NotificationRecord, Object},
+    error::{Error, Result},
+    utils::trim_bytes,
+    Minio,
+};
 use async_stream::stream as Stream2;
+use bytes::BytesMut;
 use futures_core::Stream;
-use futures_util::{stream, StreamExt};
+use futures_util::{stream, StreamExt, TryStreamExt};
+use hyper::Method;
 
-use super::{BucketArgs, ListObjectsArgs};
+use super::{BucketArgs, BulkResult, ListObjectsArgs, ObjectStat, Tags};
 
 /// Added extension operate.
 /// All operations are experimental.
@@ -60,4 +68,274 @@ impl Minio {
             })
         }))
     }
+
+    /// Reads the keys of all objects starting with the prefix of the bucket.
+    /// Returns an async stream of object keys, lighter than [list_objects_stream](Self::list_objects_stream).
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// use futures_util::{stream, StreamExt};
+    ///
+    /// # async fn example(minio: Minio){
+    /// let mut keys = minio.list_object_keys_stream("bucket".into(), "videos/");
+    /// while let Some(key) = keys.next().await{
+    ///  // .....
+    /// }
+    /// # }
+    /// ```
+    pub fn list_object_keys_stream<'a>(
+        &'a self,
+        bucket: BucketArgs,
+        prefix: &'a str,
+    ) -> Pin<Box<dyn Stream<Item = Result<String>> + Send + 'a>> {
+        Box::pin(
+            self.list_objects_stream(bucket, prefix)
+                .map_ok(|obj| obj.key),
+        )
+    }
+
+    /// Reads all objects starting with the prefix of the bucket, following the pagination.
+    ///
+    /// All objects are collected in memory before returning,
+    /// use [list_objects_stream](Self::list_objects_stream) for buckets with many objects.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let objs = minio.list_objects_all("bucket".into(), "videos/").await?;
+    /// for obj in objs {
+    ///     println!("{}: {}", obj.key, obj.size);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_objects_all(&self, bucket: BucketArgs, prefix: &str) -> Result<Vec<Object>> {
+        self.list_objects_stream(bucket, prefix).try_collect().await
+    }
+
+    /// Gets the [ObjectStat] of the keys of the bucket,
+    /// at most `concurrency` HEAD requests are sent at the same time.
+    ///
+    /// Returns the key and the result of [stat_object](Minio::stat_object) of each key, in the order of `keys`.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// # async fn example(minio: Minio){
+    /// let keys = vec!["a.txt".to_string(), "b.txt".to_string()];
+    /// for (key, stat) in minio.stat_objects("bucket".into(), keys, 8).await {
+    ///     match stat {
+    ///         Ok(Some(stat)) => println!("{key}: {}", stat.size()),
+    ///         Ok(None) => println!("{key}: not found"),
+    ///         Err(err) => println!("{key}: {err}"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn stat_objects(
+        &self,
+        bucket: BucketArgs,
+        keys: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<(String, Result<Option<ObjectStat>>)> {
+        stream::iter(keys)
+            .map(|key| {
+                let bucket = bucket.clone();
+                async move {
+                    let res = self.stat_object(bucket, key.as_str()).await;
+                    (key, res)
+                }
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Listens to the events of the objects of the bucket, through the MinIO specific `ListenBucketNotification` API.
+    ///
+    /// The request is held open by the server, and the records are yielded as the events arrive.
+    /// The request is sent again if the server closes the connection, after a delay which doubles
+    /// from 100 milliseconds up to 30 seconds while the connections end without records.
+    /// The stream ends with the error if a request fails, the connection breaks,
+    /// or a line of the response is not a valid JSON record.
+    ///
+    /// - `prefix` and `suffix`: filter the object keys, ignored if empty.
+    /// - `events`: the event types, like `s3:ObjectCreated:*` and `s3:ObjectRemoved:*`.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example(minio: Minio){
+    /// let events = vec!["s3:ObjectCreated:*".to_string()];
+    /// let mut records = minio.listen_bucket_notification("bucket".into(), "videos/", ".mp4", events);
+    /// while let Some(Ok(record)) = records.next().await {
+    ///     println!("{} {}", record.event_name, record.s3.object.key);
+    /// }
+    /// # }
+    /// ```
+    pub fn listen_bucket_notification<'a>(
+        &'a self,
+        bucket: BucketArgs,
+        prefix: &'a str,
+        suffix: &'a str,
+        events: Vec<String>,
+    ) -> Pin<Box<dyn Stream<Item = Result<NotificationRecord>> + Send + 'a>> {
+        const MIN_DELAY: Duration = Duration::from_millis(100);
+        const MAX_DELAY: Duration = Duration::from_secs(30);
+        let stm = Stream2!({
+            let mut delay = MIN_DELAY;
+            loop {
+                let res = self
+                    ._bucket_executor(bucket.clone(), Method::GET)
+                    .apply(|mut e| {
+                        if !prefix.is_empty() {
+                            e = e.query("prefix", prefix);
+                        }
+                        if !suffix.is_empty() {
+                            e = e.query("suffix", suffix);
+                        }
+                        for event in &events {
+                            e = e.query("events", event);
+                        }
+                        e
+                    })
+                    .send_ok()
+                    .await;
+                let mut body = match res {
+                    Ok(res) => res.bytes_stream(),
+                    Err(e) => {
+                        yield Err(e);
+                        return;
+                    }
+                };
+                // the records are separated by newlines, and blank lines are sent to keep the connection alive.
+                let mut buf = BytesMut::new();
+                while let Some(chunk) = body.next().await {
+                    let chunk = match chunk {
+                        Ok(chunk) => chunk,
+                        Err(e) => {
+                            yield Err(e.into());
+                            return;
+                        }
+                    };
+                    buf.extend_from_slice(&chunk);
+                    while let Some(i) = buf.iter().position(|&b| b == b'\n') {
+                        let line = buf.split_to(i + 1);
+                        let line = trim_bytes(&line);
+                        if line.is_empty() {
+                            continue;
+                        }
+                        match serde_json::from_slice::<NotificationInfo>(line) {
+                            Ok(info) => {
+                                for record in info.records.unwrap_or_default() {
+                                    delay = MIN_DELAY;
+                                    yield Ok(record);
+                                }
+                            }
+                            Err(e) => {
+                                yield Err(Error::Deserialize(e));
+                                return;
+                            }
+                        }
+                    }
+                }
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(MAX_DELAY);
+            }
+        });
+        Box::pin(stm)
+    }
+
+    /// Removes the objects of the keys produced by the stream,
+    /// the keys are sent in batches of 1000 as soon as the stream produces them.
+    ///
+    /// Returns an async stream of the objects failed to be removed,
+    /// or the error of a failed batch request.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// use futures_util::{future, StreamExt};
+    ///
+    /// # async fn example(minio: Minio){
+    /// let keys = minio
+    ///     .list_object_keys_stream("bucket".into(), "videos/")
+    ///     .filter_map(|key| future::ready(key.ok()));
+    /// let mut errors = minio.remove_objects_stream("bucket".into(), keys);
+    /// while let Some(err) = errors.next().await{
+    ///  // .....
+    /// }
+    /// # }
+    /// ```
+    pub fn remove_objects_stream<'a, S>(
+        &'a self,
+        bucket: BucketArgs,
+        keys: S,
+    ) -> Pin<Box<dyn Stream<Item = Result<DeleteError>> + Send + 'a>>
+    where
+        S: Stream<Item = String> + Send + 'a,
+    {
+        let stm = keys.chunks(1000).then(move |keys| {
+            let bucket = bucket.clone();
+            async move { self.remove_objects(bucket, keys).await }
+        });
+        Box::pin(stm.flat_map(|f| {
+            stream::iter(match f {
+                Ok(f) => f.into_iter().map(Result::Ok).collect::<Vec<_>>(),
+                Err(e) => vec![Err(e)],
+            })
+        }))
+    }
+
+    /// Sets the tags of all objects starting with the prefix of the bucket,
+    /// at most `concurrency` objects are tagged at the same time.
+    ///
+    /// Returns an error if listing the objects fails,
+    /// otherwise the objects failed to be tagged are reported in the [BulkResult].
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// use sc_minio::client::Tags;
+    ///
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let mut tags = Tags::new();
+    /// tags.insert("cost-center", "video");
+    /// let result = minio
+    ///     .set_object_tags_recursive("bucket".into(), "videos/", tags, 8)
+    ///     .await?;
+    /// for (key, err) in result.failed() {
+    ///     println!("{key}: {err}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_object_tags_recursive<T: Into<Tags>>(
+        &self,
+        bucket: BucketArgs,
+        prefix: &str,
+        tags: T,
+        concurrency: usize,
+    ) -> Result<BulkResult> {
+        let tags: Tags = tags.into();
+        tags.validate(10)?;
+        let mut results = self
+            .list_object_keys_stream(bucket.clone(), prefix)
+            .map_ok(|key| {
+                let (bucket, tags) = (bucket.clone(), tags.clone());
+                async move {
+                    let res = self.set_object_tags(bucket, key.as_str(), tags).await;
+                    Ok((key, res))
+                }
+            })
+            .try_buffer_unordered(concurrency.max(1));
+        let mut result = BulkResult::default();
+        while let Some((key, res)) = results.try_next().await? {
+            match res {
+                Ok(_) => result.succeeded.push(key),
+                Err(e) => result.failed.push((key, e)),
+            }
+        }
+        Ok(result)
+    }
 }
@@ -4,17 +4,150 @@ use std::path::Path;
 use std::pin::Pin;
 
 use bytes::{Bytes, BytesMut};
-use futures::{Stream, StreamExt};
+use futures::{Stream, StreamExt, TryStreamExt};
+use hyper::header::HeaderValue;
 use hyper::{header, HeaderMap, Method};
 use reqwest::Response;
 
-use super::{BucketArgs, CopySource, KeyArgs, ObjectStat, SelectObjectReader, Tags};
-use crate::datatype::{AccessControlPolicy, LegalHold, Retention};
-use crate::datatype::{LegalHoldStatus, SelectRequest};
-use crate::error::{Error, Result, S3Error, ValueError};
-use crate::signer::{MAX_MULTIPART_OBJECT_SIZE, MIN_PART_SIZE};
+use super::ObjectDescription;
+use super::{BucketArgs, CopySource, GetObjectResponse, KeyArgs, MetadataDirective, ObjectStat};
+use super::{MultipartUploadGuard, MultipartUploadTask, PutObjectResult};
+use super::{RemoveObjectResult, UploadSource};
+use super::{SelectObjectReader, Tags};
+use crate::datatype::{AccessControlPolicy, ChecksumAlgorithm, LegalHold, Retention};
+use crate::datatype::{DeleteError, DeleteResult};
+use crate::datatype::{FromXml, ObjectAttribute, ObjectAttributes};
+use crate::datatype::{LegalHoldStatus, SelectRequest, StorageClass};
+use crate::error::{Error, Result, ValueError};
+use crate::utils::{xml_escape, ChecksumHasher};
 use crate::Minio;
 
+const SSEC_KEY_MD5: &str = "x-amz-server-side-encryption-customer-key-md5";
+
+/// Check the SSE-C headers echoed by the S3 server against the MD5 of the customer key sent.
+fn check_ssec_response(
+    res: &Response,
+    key_md5: Option<&str>,
+) -> std::result::Result<(), ValueError> {
+    let echoed = res
+        .headers()
+        .get(SSEC_KEY_MD5)
+        .and_then(|x| x.to_str().ok());
+    match (echoed, key_md5) {
+        (Some(echoed), Some(key_md5)) if echoed != key_md5 => Err(ValueError::new(format!(
+            "the object is encrypted with another SSE-C key, key MD5 {echoed}, sent {key_md5}."
+        ))),
+        (Some(_), None) => Err(ValueError::from(
+            "the object is encrypted with SSE-C, but no customer key was sent.",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Return the absolute byte range `(start, end)` of a `206 Partial Content` response,
+/// [None] if the response is not partial or has no valid `Content-Range`.
+fn partial_content_range(res: &Response) -> Option<(usize, usize)> {
+    if res.status() != hyper::StatusCode::PARTIAL_CONTENT {
+        return None;
+    }
+    let (range, _) = res
+        .headers()
+        .get(header::CONTENT_RANGE)?
+        .to_str()
+        .ok()?
+        .strip_prefix("bytes ")?
+        .split_once('/')?;
+    let (start, end) = range.split_once('-')?;
+    Some((start.parse().ok()?, end.parse().ok()?))
+}
+
+/// Return the hasher of the trailing checksum, a [ValueError] if the algorithm is not supported.
+fn trailing_checksum_hasher(
+    algorithm: &ChecksumAlgorithm,
+) -> std::result::Result<ChecksumHasher, ValueError> {
+    ChecksumHasher::new(algorithm).ok_or_else(|| {
+        ValueError::new(format!(
+            "{algorithm:?} is not supported by trailing checksum"
+        ))
+    })
+}
+
+/// Replace the body of the response with the content decoded by `Content-Encoding`.
+///
+/// The response is returned unchanged if the encoding is neither `gzip` nor `deflate`.
+#[cfg(feature = "compress")]
+fn decompress_response(res: Response) -> Response {
+    use async_compression::futures::bufread::{GzipDecoder, ZlibDecoder};
+    use futures::{AsyncRead, AsyncReadExt, TryStreamExt};
+
+    let encoding = res
+        .headers()
+        .get(header::CONTENT_ENCODING)
+        .and_then(|x| x.to_str().ok())
+        .map(|x| x.trim().to_ascii_lowercase());
+    let is_gzip = match encoding.as_deref() {
+        Some("gzip" | "x-gzip") => true,
+        Some("deflate") => false,
+        _ => return res,
+    };
+    let mut headers = res.headers().clone();
+    headers.remove(header::CONTENT_ENCODING);
+    headers.remove(header::CONTENT_LENGTH);
+    let builder = hyper::Response::builder()
+        .status(res.status())
+        .version(res.version());
+    let reader = res
+        .bytes_stream()
+        .map_err(std::io::Error::other)
+        .into_async_read();
+    let decoder: Pin<Box<dyn AsyncRead + Send>> = if is_gzip {
+        Box::pin(GzipDecoder::new(reader))
+    } else {
+        Box::pin(ZlibDecoder::new(reader))
+    };
+    let stream = futures::stream::try_unfold(decoder, |mut decoder| async move {
+        let mut buf = vec![0; 8192];
+        let n = decoder.read(&mut buf).await?;
+        if n == 0 {
+            return Ok::<_, std::io::Error>(None);
+        }
+        buf.truncate(n);
+        Ok(Some((Bytes::from(buf), decoder)))
+    });
+    let mut response = builder
+        .body(reqwest::Body::wrap_stream(stream))
+        .expect("status and version are copied from a valid response");
+    *response.headers_mut() = headers;
+    Response::from(response)
+}
+
+/// Gzip the content of the stream.
+#[cfg(feature = "compress")]
+fn gzip_stream(
+    stream: Pin<Box<dyn Stream<Item = Result<Bytes>> + Sync + Send>>,
+) -> Pin<Box<dyn Stream<Item = Result<Bytes>> + Sync + Send>> {
+    use async_compression::futures::bufread::GzipEncoder;
+    use futures::{AsyncReadExt, TryStreamExt};
+
+    let mut encoder = GzipEncoder::new(stream.map_err(std::io::Error::other).into_async_read());
+    Box::pin(async_stream::stream! {
+        loop {
+            let mut buf = vec![0; crate::signer::RECOMMEND_CHUNK_SIZE];
+            match encoder.read(&mut buf).await {
+                Ok(0) => break,
+                Ok(n) => {
+                    buf.truncate(n);
+                    yield Ok(Bytes::from(buf));
+                }
+                Err(e) => {
+                    yield Err(e.into());
+                    break;
+                }
+            }
+        }
+    })
+}
+
 /// Operating the object
 impl Minio {
     #[inline]
@@ -36,6 +169,7 @@ impl Minio {
             ._bucket_executor(bucket, method)
             .object_name(key.name)
             .headers_merge2(key.extra_headers)
+            .querys_merge(key.extra_querys)
             .apply(|mut e| {
                 if let Some(version_id) = key.version_id {
                     e = e.query("versionId", version_id)
@@ -55,6 +189,18 @@ impl Minio {
                 if with_sscs {
                     e = e.headers_merge2(key.ssec_headers);
                 }
+                if key.request_payer {
+                    e = e.header("x-amz-request-payer", "requester");
+                }
+                if key.checksum_mode {
+                    e = e.header("x-amz-checksum-mode", "ENABLED");
+                }
+                if let Some(etag) = key.if_match {
+                    e = e.header(header::IF_MATCH, etag);
+                }
+                if key.if_none_match {
+                    e = e.header(header::IF_NONE_MATCH, "*");
+                }
                 e
             });
         Ok(executor)
@@ -65,7 +211,8 @@ impl Minio {
     /// ``` rust
     /// # use sc_minio::Minio;
     /// use sc_minio::error::Result;
-    /// use sc_minio::client::{CopySource, KeyArgs};
+    /// use sc_minio::client::{CopySource, KeyArgs, MetadataDirective, TaggingDirective};
+    /// use std::collections::HashMap;
     ///
     /// # async fn example(minio: Minio)->Result<()>{
     /// let src = CopySource::new("bucket","key1");
@@ -74,6 +221,13 @@ impl Minio {
     /// let dst = KeyArgs::new("key2").content_type(Some("image/jpeg".to_string()));
     /// let src = CopySource::new("bucket","key1").metadata_replace(true);
     /// let response = minio.copy_object("bucket", dst, src).await?;
+    /// // replace metadata and tags
+    /// let metadata: HashMap<String, String> = [("filename".to_owned(), "key3".to_owned())].into();
+    /// let dst = KeyArgs::new("key3").metadata(metadata);
+    /// let src = CopySource::new("bucket","key1")
+    ///     .metadata_directive(MetadataDirective::Replace)
+    ///     .tagging_directive(TaggingDirective::Replace);
+    /// let response = minio.copy_object("bucket", dst, src).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -90,7 +244,215 @@ impl Minio {
             .map(|_| ())
     }
 
+    /// Moves an object by copying it on the server side, then removing the source object.
+    ///
+    /// The source object is removed only if the copy succeeded.
+    /// The metadata and tags are preserved unless the directives of the [CopySource] are set to replace them.
+    /// ## Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// use sc_minio::error::Result;
+    /// use sc_minio::client::CopySource;
+    ///
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let src = CopySource::new("bucket", "inbox/file.txt");
+    /// minio.move_object("archive", "2024/file.txt", src).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn move_object<B, K>(&self, bucket: B, key: K, src: CopySource) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        let (bucket, key): (BucketArgs, KeyArgs) = (bucket.into(), key.into());
+        let (src_bucket, src_key) = src.source_args();
+        if src_bucket.name == bucket.name
+            && src_key.name == key.name
+            && src_key.version_id.is_none()
+        {
+            return Err(ValueError::from("cannot move an object to itself").into());
+        }
+        self.copy_object(bucket, key, src).await?;
+        self.remove_object(src_bucket, src_key).await.map(|_| ())
+    }
+
+    /// Changes the storage class of an object in place, by copying the object to itself
+    /// with the `x-amz-storage-class` header and [MetadataDirective::Copy].
+    ///
+    /// The metadata, content type and tags are preserved.
+    /// Set `version_id` of `key` to re-tier a specific version of the object.
+    ///
+    /// **Note**: the copy changes the last modified time of the object, the ETag is also
+    /// changed if the object was uploaded in parts or encrypted with SSE-KMS or SSE-C.
+    /// In versioned buckets a new version is created with the storage class,
+    /// the storage class of the previous versions is unchanged.
+    /// ## Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// use sc_minio::error::Result;
+    /// use sc_minio::datatype::StorageClass;
+    ///
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// minio.set_object_storage_class("bucket", "file.txt", StorageClass::Glacier).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_object_storage_class<B, K>(
+        &self,
+        bucket: B,
+        key: K,
+        class: StorageClass,
+    ) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        let (bucket, mut key): (BucketArgs, KeyArgs) = (bucket.into(), key.into());
+        let mut src = CopySource::new(bucket.name.as_str(), key.name.as_str())
+            .region(bucket.region.clone())
+            .expected_bucket_owner(bucket.expected_bucket_owner.clone())
+            .metadata_directive(MetadataDirective::Copy);
+        if let Some(version_id) = key.version_id.take() {
+            src = src.version_id(version_id);
+        }
+        key.extra_headers.get_or_insert_with(HeaderMap::new).insert(
+            "x-amz-storage-class",
+            HeaderValue::from_static(class.as_str()),
+        );
+        self.copy_object(bucket, key, src).await
+    }
+
+    /// Replaces the user-defined metadata of an object in place, by copying the object to itself
+    /// with [MetadataDirective::Replace].
+    ///
+    /// The tags are preserved. The content type is preserved unless it is set in `key`.
+    ///
+    /// **Note**: the copy changes the ETag and last modified time of the object,
+    /// and creates a new version in versioned buckets.
+    /// ## Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// use sc_minio::error::Result;
+    /// use std::collections::HashMap;
+    ///
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let metadata: HashMap<String, String> = [("filename".to_owned(), "file.txt".to_owned())].into();
+    /// minio.update_object_metadata("bucket", "file.txt", metadata).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_object_metadata<B, K>(
+        &self,
+        bucket: B,
+        key: K,
+        metadata: HashMap<String, String>,
+    ) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        let (bucket, mut key): (BucketArgs, KeyArgs) = (bucket.into(), key.into());
+        if key.content_type.is_none() {
+            if let Some(stat) = self.stat_object(bucket.clone(), key.clone()).await? {
+                if !stat.content_type().is_empty() {
+                    key.content_type = Some(stat.content_type().to_owned());
+                }
+            }
+        }
+        let mut src = CopySource::new(bucket.name.as_str(), key.name.as_str())
+            .region(bucket.region.clone())
+            .expected_bucket_owner(bucket.expected_bucket_owner.clone())
+            .metadata_directive(MetadataDirective::Replace);
+        if let Some(version_id) = key.version_id.take() {
+            src = src.version_id(version_id);
+        }
+        self.copy_object(bucket, key.metadata(metadata), src).await
+    }
+
+    /// Return the body of the response of `get_object` as a stream.
+    ///
+    /// With [KeyArgs::resilient], a ranged GET is sent from the last received byte if the body fails,
+    /// with `If-Match` of the ETag of the response, so the parts come from the same object.
+    /// The stream ends with [Error::UnknownResponse] if the ranged GET is not answered by
+    /// `206 Partial Content` from the last received byte.
+    fn _resume_body<'a>(
+        &'a self,
+        bucket: BucketArgs,
+        key: KeyArgs,
+        res: Response,
+    ) -> Pin<Box<dyn Stream<Item = Result<Bytes>> + Send + 'a>> {
+        use async_stream::stream;
+
+        #[cfg(feature = "compress")]
+        let resilient = key.resilient && !key.auto_decompress;
+        #[cfg(not(feature = "compress"))]
+        let resilient = key.resilient;
+        if !resilient || key.part_number.is_some() {
+            return Box::pin(res.bytes_stream().map_err(Error::from));
+        }
+        let etag = res
+            .headers()
+            .get(header::ETAG)
+            .and_then(|x| x.to_str().ok())
+            .map(String::from);
+        let (start, end) = partial_content_range(&res)
+            .map(|(start, end)| (start, Some(end)))
+            .unwrap_or((0, None));
+        Box::pin(stream! {
+            let mut body = res.bytes_stream();
+            let mut offset = start;
+            let mut retries = 0;
+            loop {
+                match body.next().await {
+                    Some(Ok(chunk)) => {
+                        offset += chunk.len();
+                        yield Ok(chunk);
+                    }
+                    Some(Err(_err)) if retries < self.resume_retries() => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(error = %_err, offset, "resume the download of the object");
+                        retries += 1;
+                        let mut key = key.clone();
+                        key.offset = offset;
+                        key.length = end.map(|end| end + 1 - offset).unwrap_or(0);
+                        key.range_suffix = None;
+                        key.if_match = etag.clone();
+                        match self.get_object(bucket.clone(), key).await {
+                            Ok(res) if res.status().is_success() => {
+                                if partial_content_range(&res).map(|(start, _)| start) != Some(offset) {
+                                    yield Err(Error::UnknownResponse(res));
+                                    return;
+                                }
+                                body = res.bytes_stream();
+                            }
+                            Ok(res) => {
+                                yield match self._read_error(res).await {
+                                    Ok(e) => Err(e.into()),
+                                    Err(e) => Err(e),
+                                };
+                                return;
+                            }
+                            Err(e) => {
+                                yield Err(e);
+                                return;
+                            }
+                        }
+                    }
+                    Some(Err(err)) => {
+                        yield Err(err.into());
+                        return;
+                    }
+                    None => return,
+                }
+            }
+        })
+    }
+
     /// Downloads data of an object to file.
+    ///
+    /// If the download fails in the middle of the response, the partial file is removed
+    /// and the error is returned, unless the download is resumed by [KeyArgs::resilient].
     /// # Exapmle
     /// ``` rust
     /// # use sc_minio::Minio;
@@ -108,26 +470,282 @@ impl Minio {
         K: Into<KeyArgs>,
         P: AsRef<Path>,
     {
-        use tokio::{fs::File, io::AsyncWriteExt};
+        use tokio::{fs, io::AsyncWriteExt};
 
-        let res = self.get_object(bucket, key).await?;
+        let (bucket, key): (BucketArgs, KeyArgs) = (bucket.into(), key.into());
+        let res = self.get_object(bucket.clone(), key.clone()).await?;
         if !res.status().is_success() {
-            let text = res.text().await?;
-            let s3err: S3Error = text.as_str().try_into()?;
-            Err(s3err)?
+            Err(self._read_error(res).await?)?
+        } else {
+            let path = path.as_ref();
+            let mut stream = self._resume_body(bucket, key, res);
+            let mut file = fs::File::create(path).await?;
+            let result = async {
+                while let Some(item) = stream.next().await {
+                    file.write_all(&item?).await?;
+                }
+                file.flush().await?;
+                Ok(())
+            }
+            .await;
+            if result.is_err() {
+                drop(file);
+                fs::remove_file(path).await.ok();
+            }
+            result
+        }
+    }
+
+    /// Downloads data of an object to a writer, return the number of bytes written.
+    ///
+    /// The download is resumed by [KeyArgs::resilient] if the connection drops.
+    /// # Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// use sc_minio::client::KeyArgs;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let mut buf = vec![];
+    /// let key = KeyArgs::new("file.txt").resilient(true);
+    /// let size = minio.get_object_to_writer("bucket", key, &mut buf).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_object_to_writer<B, K, W>(
+        &self,
+        bucket: B,
+        key: K,
+        writer: &mut W,
+    ) -> Result<usize>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+        W: futures::AsyncWrite + Unpin + ?Sized,
+    {
+        use futures::AsyncWriteExt;
+
+        let (bucket, key): (BucketArgs, KeyArgs) = (bucket.into(), key.into());
+        let res = self.get_object(bucket.clone(), key.clone()).await?;
+        if !res.status().is_success() {
+            return Err(self._read_error(res).await?.into());
+        }
+        let mut stream = self._resume_body(bucket, key, res);
+        let mut size = 0;
+        while let Some(item) = stream.next().await {
+            let chunk = item?;
+            writer.write_all(&chunk).await?;
+            size += chunk.len();
+        }
+        writer.flush().await?;
+        Ok(size)
+    }
+
+    /// Download an object to a local file by ranged GET requests running in parallel.
+    ///
+    /// The size of the object is fetched by [Minio::stat_object] first,
+    /// then at most `concurrency` parts of `part_size` bytes are downloaded at the same time
+    /// and written into their offsets of the file.
+    /// Objects not larger than `part_size` are downloaded by [Minio::fget_object].
+    ///
+    /// If the object has a full-object checksum, the file is read again
+    /// after the download to verify it, the file is removed and [Error::ChecksumMismatch]
+    /// is returned if the checksum does not match.
+    /// Composite checksums of multipart uploads cannot be verified for the whole object,
+    /// neither can SHA-1 checksums if the [CryptoProvider](crate::crypto::CryptoProvider) has no SHA-1.
+    ///
+    /// The whole object is downloaded, a [ValueError] is returned if the `key` has a range or a part number.
+    /// [Error::UnknownResponse] is returned if a part is not answered by `206 Partial Content`
+    /// with the requested `Content-Range`, e.g. by a proxy ignoring `Range`.
+    /// # Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// minio.fget_object_concurrent("bucket", "file.txt", "local_file.txt", 4, 16 * 1024 * 1024).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs-tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fs-tokio")))]
+    pub async fn fget_object_concurrent<B, K, P>(
+        &self,
+        bucket: B,
+        key: K,
+        path: P,
+        concurrency: usize,
+        part_size: usize,
+    ) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+        P: AsRef<Path>,
+    {
+        use std::io::SeekFrom;
+        use tokio::fs::{File, OpenOptions};
+        use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+        if concurrency == 0 || part_size == 0 {
+            return Err(ValueError::from("concurrency and part_size must be positive").into());
+        }
+        let bucket: BucketArgs = bucket.into();
+        let mut key: KeyArgs = key.into();
+        if key.offset > 0
+            || key.length > 0
+            || key.range_suffix.is_some()
+            || key.part_number.is_some()
+        {
+            return Err(ValueError::from(
+                "the range and part number of the key are not supported by concurrent download",
+            )
+            .into());
+        }
+        let stat = self
+            .stat_object(bucket.clone(), key.clone().checksum_mode(true))
+            .await?;
+        let stat = match stat {
+            Some(stat) if stat.size() > part_size => stat,
+            _ => return self.fget_object(bucket, key, path).await,
+        };
+        // pin the version, so that all parts come from the same object.
+        if key.version_id.is_none() && !stat.version_id().is_empty() {
+            key.version_id = Some(stat.version_id().to_owned());
+        }
+        let size = stat.size();
+        let path = path.as_ref();
+        File::create(path).await?.set_len(size as u64).await?;
+
+        let parts = (0..size).step_by(part_size).map(|offset| {
+            let length = part_size.min(size - offset);
+            let key = key.clone().offset(offset).length(length);
+            let bucket = bucket.clone();
+            async move {
+                let res = self.get_object(bucket, key).await?;
+                if !res.status().is_success() {
+                    return Err(self._read_error(res).await?.into());
+                }
+                if partial_content_range(&res) != Some((offset, offset + length - 1)) {
+                    return Err(Error::UnknownResponse(res));
+                }
+                let mut file = OpenOptions::new().write(true).open(path).await?;
+                file.seek(SeekFrom::Start(offset as u64)).await?;
+                let mut stream = res.bytes_stream();
+                while let Some(item) = stream.next().await {
+                    file.write_all(&item?).await?;
+                }
+                file.flush().await?;
+                Ok::<(), Error>(())
+            }
+        });
+        let mut tasks = futures::stream::iter(parts).buffer_unordered(concurrency);
+        while let Some(res) = tasks.next().await {
+            res?;
+        }
+        let hasher = stat
+            .full_object_checksum()
+            .and_then(|(algorithm, expected)| Some((ChecksumHasher::new(algorithm)?, expected)));
+        if let Some((mut hasher, expected)) = hasher {
+            let mut file = File::open(path).await?;
+            let mut buf = vec![0; 1024 * 1024];
+            loop {
+                let n = file.read(&mut buf).await?;
+                if n == 0 {
+                    break;
+                }
+                hasher.update(&buf[..n]);
+            }
+            let actual = hasher.finalize();
+            if actual != expected {
+                tokio::fs::remove_file(path).await.ok();
+                return Err(Error::ChecksumMismatch(format!(
+                    "checksum mismatch of {}, expected {expected}, got {actual}",
+                    stat.object_name()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Download an object to a local file, resuming a previous partial download.
+    ///
+    /// The ETag of the object is saved to `{path}.etag` while downloading.
+    /// If `path` already exists and the ETag has not changed, only the missing tail
+    /// of the object is requested by `Range`, otherwise the download restarts from scratch.
+    /// The download also restarts from scratch if the tail is not answered by `206 Partial Content`
+    /// from the end of the file, e.g. by a proxy ignoring `Range`.
+    /// The `{path}.etag` file is removed after the download completes.
+    /// # Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// minio.fget_object_resumable("bucket", "file.txt", "local_file.txt").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs-tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fs-tokio")))]
+    pub async fn fget_object_resumable<B, K, P>(&self, bucket: B, key: K, path: P) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+        P: AsRef<Path>,
+    {
+        use tokio::fs::{self, OpenOptions};
+        use tokio::io::AsyncWriteExt;
+
+        let bucket: BucketArgs = bucket.into();
+        let mut key: KeyArgs = key.into();
+        let stat = match self.stat_object(bucket.clone(), key.clone()).await? {
+            Some(stat) => stat,
+            None => return self.fget_object(bucket, key, path).await,
+        };
+        let path = path.as_ref();
+        let mut etag_path = path.as_os_str().to_owned();
+        etag_path.push(".etag");
+
+        let existing_len = match fs::metadata(path).await {
+            Ok(meta) => meta.len() as usize,
+            Err(_) => 0,
+        };
+        let saved_etag = fs::read_to_string(&etag_path).await.unwrap_or_default();
+        let offset = if saved_etag == stat.etag() && existing_len <= stat.size() {
+            existing_len
         } else {
+            0
+        };
+        fs::write(&etag_path, stat.etag()).await?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(offset > 0)
+            .truncate(offset == 0)
+            .open(path)
+            .await?;
+
+        if offset < stat.size() {
+            if key.version_id.is_none() && !stat.version_id().is_empty() {
+                key.version_id = Some(stat.version_id().to_owned());
+            }
+            let key = key.offset(offset).length(0).if_match(stat.etag());
+            let res = self.get_object(bucket, key).await?;
+            if offset > 0 && partial_content_range(&res).map(|(start, _)| start) != Some(offset) {
+                // the whole object is sent, the file is appended from the start.
+                file.set_len(0).await?;
+            }
             let mut stream = res.bytes_stream();
-            let mut file = File::create(path).await?;
             while let Some(item) = stream.next().await {
-                if let Ok(datas) = item {
-                    file.write_all(&datas).await?;
-                }
+                file.write_all(&item?).await?;
             }
-            Ok(())
+            file.flush().await?;
         }
+        fs::remove_file(&etag_path).await?;
+        Ok(())
     }
 
     /// Get [reqwest::Response] of an object.
+    ///
+    /// An object encrypted with SSE-C can only be read with the same customer key set by [KeyArgs::ssec],
+    /// a [ValueError] is returned if the key is missing or does not match the key echoed by the server.
     /// ## Exapmle
     /// ``` rust
     /// use reqwest::Response;
@@ -148,8 +766,18 @@ impl Minio {
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
-        let range = key.range();
-        self._object_executor(Method::GET, bucket, key, true, true)?
+        let range = key.range()?;
+        let part_number = key.part_number;
+        let ssec_key_md5 = key
+            .ssec_headers
+            .as_ref()
+            .and_then(|x| x.get(SSEC_KEY_MD5))
+            .and_then(|x| x.to_str().ok())
+            .map(String::from);
+        #[cfg(feature = "compress")]
+        let auto_decompress = key.auto_decompress;
+        let res = self
+            ._object_executor(Method::GET, bucket, key, true, true)?
             .apply(|e| {
                 if let Some(range) = range {
                     e.header(header::RANGE, &range)
@@ -157,8 +785,95 @@ impl Minio {
                     e
                 }
             })
+            .apply(|e| match part_number {
+                Some(part_number) => e.query("partNumber", part_number.to_string()),
+                None => e,
+            })
             .send_ok()
+            .await;
+        let res = match res {
+            Ok(res) => res,
+            Err(Error::S3Error(s))
+                if ssec_key_md5.is_none()
+                    && s.code == "InvalidRequest"
+                    && s.message.contains("Server Side Encryption") =>
+            {
+                Err(ValueError::new(format!(
+                    "the object is encrypted with SSE-C, set the customer key by KeyArgs::ssec: {}",
+                    s.message
+                )))?
+            }
+            Err(err) => return Err(err),
+        };
+        check_ssec_response(&res, ssec_key_md5.as_deref())?;
+        #[cfg(feature = "compress")]
+        if auto_decompress {
+            return Ok(decompress_response(res));
+        }
+        Ok(res)
+    }
+
+    /// Get the data of an object as a [GetObjectResponse],
+    /// whose status and headers can be inspected before reading the body.
+    /// ## Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let res = minio.get_object_response("bucket", "file.txt").await?;
+    /// if res.content_type() == Some("text/plain") {
+    ///     println!("{:?} {:?}", res.etag(), res.last_modified());
+    ///     let text = res.text().await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_object_response<B, K>(&self, bucket: B, key: K) -> Result<GetObjectResponse>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        self.get_object(bucket, key)
             .await
+            .map(GetObjectResponse::from)
+    }
+
+    /// Get the content of an object as a [String].
+    ///
+    /// Returns [Error::Encoding] if the content is not valid UTF-8,
+    /// or the `charset` of the response `Content-Type` is neither `utf-8` nor `us-ascii`.
+    /// ## Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let text: String = minio.get_object_string("bucket", "file.txt").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_object_string<B, K>(&self, bucket: B, key: K) -> Result<String>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        let res = self.get_object(bucket, key).await?;
+        let charset = res
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|x| x.to_str().ok())
+            .and_then(|x| {
+                x.split(';')
+                    .filter_map(|p| p.trim().split_once('='))
+                    .find(|(k, _)| k.trim().eq_ignore_ascii_case("charset"))
+                    .map(|(_, v)| v.trim().trim_matches('"').to_ascii_lowercase())
+            });
+        if let Some(charset) = charset {
+            if !matches!(charset.as_str(), "utf-8" | "utf8" | "us-ascii") {
+                return Err(Error::Encoding(format!("unsupported charset: {charset}")));
+            }
+        }
+        let data = res.bytes().await?;
+        String::from_utf8(data.into()).map_err(|e| Error::Encoding(e.to_string()))
     }
 
     /// Get torrent files from a bucket.
@@ -175,38 +890,302 @@ impl Minio {
             .await
     }
 
-    /// Uploads data to an object in a bucket.
+    /// Create a directory marker, a zero-byte object named `prefix/`,
+    /// which is shown as an empty folder by the MinIO console and many tools.
+    ///
+    /// Use [ListBucketResult::directories](crate::datatype::ListBucketResult::directories) to list them.
+    /// ## Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// use sc_minio::client::ListObjectsArgs;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// minio.create_object_prefix("bucket", "photos/2024").await?;
+    /// let args = ListObjectsArgs::default().prefix("photos/").delimiter("/");
+    /// let res = minio.list_objects("bucket", args).await?;
+    /// assert!(res.directories().contains(&"photos/2024/"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_object_prefix<B>(&self, bucket: B, prefix: &str) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let prefix = prefix.trim_end_matches('/');
+        if prefix.is_empty() {
+            return Err(ValueError::from("prefix cannot be empty").into());
+        }
+        self.put_object(bucket, format!("{prefix}/"), Bytes::new())
+            .await
+    }
+
+    /// Uploads data to an object in a bucket.
+    /// ## Exapmle
+    /// ``` rust
+    /// use reqwest::Response;
+    /// use std::collections::HashMap;
+    /// use sc_minio::client::KeyArgs;
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    ///
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let data = "hello minio";
+    /// minio.put_object("bucket", "file.txt", data.into()).await?;
+    ///
+    /// let metadata: HashMap<String, String> = [("filename".to_owned(), "file.txt".to_owned())].into();
+    /// let key = KeyArgs::new("file.txt")
+    ///             .content_type(Some("text/plain".to_string()))
+    ///             .metadata(metadata);
+    /// minio.put_object("bucket", key, data.into()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn put_object<B, K>(&self, bucket: B, key: K, data: Bytes) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        self.upload(bucket, key, UploadSource::Bytes(data))
+            .await
+            .map(|_| ())
+    }
+
+    /// Uploads data to an object in a bucket only if the object does not exist,
+    /// otherwise returns [Error::AlreadyExists].
+    /// ## Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// use sc_minio::error::{Error, Result};
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// match minio.put_object_if_not_exists("bucket", "lock", "owner".into()).await {
+    ///     Ok(_) => println!("lock acquired"),
+    ///     Err(Error::AlreadyExists(_)) => println!("lock is held by others"),
+    ///     Err(e) => return Err(e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn put_object_if_not_exists<B, K>(&self, bucket: B, key: K, data: Bytes) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        let key: KeyArgs = key.into();
+        self.put_object(bucket, key.if_none_match_star(true), data)
+            .await
+    }
+
+    /// Serializes the value to JSON and uploads it to an object in a bucket.
+    ///
+    /// The content type defaults to `application/json` if not set in the key.
+    /// ## Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// use std::collections::HashMap;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let config: HashMap<String, u32> = [("retry".to_owned(), 3)].into();
+    /// minio.put_object_json("bucket", "config.json", &config).await?;
+    /// let config: HashMap<String, u32> = minio.get_object_json("bucket", "config.json").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn put_object_json<B, K, T>(&self, bucket: B, key: K, value: &T) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+        T: serde::Serialize + ?Sized,
+    {
+        let mut key: KeyArgs = key.into();
+        if key.content_type.is_none() {
+            key.content_type = Some("application/json".to_string());
+        }
+        let data = serde_json::to_vec(value).map_err(|e| Error::ValueError(e.to_string()))?;
+        self.put_object(bucket, key, data.into()).await
+    }
+
+    /// Get an object and deserializes its JSON content.
+    ///
+    /// Returns [Error::Deserialize] if the content is not a valid JSON of `T`.
+    pub async fn get_object_json<B, K, T>(&self, bucket: B, key: K) -> Result<T>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+        T: serde::de::DeserializeOwned,
+    {
+        let res = self.get_object(bucket, key).await?;
+        let data = res.bytes().await?;
+        serde_json::from_slice(&data).map_err(Error::Deserialize)
+    }
+
+    /// Upload an object from an [UploadSource], and returns the [PutObjectResult].
+    ///
+    /// Data of unknown length is transmitted through `multipart_upload`, so is data not smaller than
+    /// the part size unless [multi_chunked_encoding](super::MinioBuilder::multi_chunked_encoding) is enabled,
+    /// and the multipart upload is aborted if any error occurs.
+    /// In-memory bytes with a [trailing checksum](KeyArgs::trailing_checksum) are sent in a single request.
     /// ## Exapmle
     /// ``` rust
-    /// use reqwest::Response;
-    /// use std::collections::HashMap;
-    /// use sc_minio::client::KeyArgs;
     /// # use sc_minio::Minio;
     /// # use sc_minio::error::Result;
-    ///
+    /// use sc_minio::client::UploadSource;
     /// # async fn example(minio: Minio)->Result<()>{
-    /// let data = "hello minio";
-    /// minio.put_object("bucket", "file.txt", data.into()).await?;
-    ///
-    /// let metadata: HashMap<String, String> = [("filename".to_owned(), "file.txt".to_owned())].into();
-    /// let key = KeyArgs::new("file.txt")
-    ///             .content_type(Some("text/plain".to_string()))
-    ///             .metadata(metadata);
-    /// minio.put_object("bucket", key, data.into()).await?;
+    /// let result = minio.upload("bucket", "file.txt", UploadSource::Bytes("hello minio".into())).await?;
+    /// println!("{}", result.etag());
+    /// let reader = futures::io::Cursor::new(b"hello minio".to_vec());
+    /// minio.upload("bucket", "file.txt", UploadSource::Reader(Box::pin(reader), Some(11))).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn put_object<B, K>(&self, bucket: B, key: K, data: Bytes) -> Result<()>
+    pub async fn upload<B, K>(
+        &self,
+        bucket: B,
+        key: K,
+        source: UploadSource,
+    ) -> Result<PutObjectResult>
     where
         B: Into<BucketArgs>,
         K: Into<KeyArgs>,
     {
-        let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
-        self._object_executor(Method::PUT, bucket, key, true, true)?
-            .body(data)
-            .send_ok()
-            .await?;
+        let if_none_match = key.if_none_match;
+        let result = self._upload(bucket.into(), key, source).await;
+        result.map_err(|e| match e {
+            Error::S3Error(e) if if_none_match && e.code == "PreconditionFailed" => {
+                Error::AlreadyExists(e)
+            }
+            e => e,
+        })
+    }
+
+    async fn _upload(
+        &self,
+        bucket: BucketArgs,
+        key: KeyArgs,
+        source: UploadSource,
+    ) -> Result<PutObjectResult> {
+        use crate::signer::RECOMMEND_CHUNK_SIZE;
+        use async_stream::stream;
+
+        match source {
+            #[cfg(feature = "compress")]
+            UploadSource::Bytes(data) if key.compression != super::Compression::None => {
+                let stream = Box::pin(futures::stream::iter([Ok(data)]));
+                self._upload_stream(bucket, key, stream, None).await
+            }
+            UploadSource::Bytes(data)
+                if data.len() >= self.part_size() && key.trailing_checksum.is_none() =>
+            {
+                let len = data.len();
+                let stream = Box::pin(futures::stream::iter([Ok(data)]));
+                self._upload_stream(bucket, key, stream, Some(len)).await
+            }
+            UploadSource::Bytes(data) => {
+                let (bucket_name, object_name) = (bucket.name.clone(), key.name.clone());
+                let with_md5 = key.has_object_lock();
+                let checksum = match &key.trailing_checksum {
+                    Some(algorithm) => {
+                        let mut hasher = trailing_checksum_hasher(algorithm)?;
+                        hasher.update(&data);
+                        Some((algorithm.header_name(), hasher.finalize()))
+                    }
+                    None => None,
+                };
+                let res = self
+                    ._object_executor(Method::PUT, bucket, key, true, true)?
+                    .apply(|e| match checksum {
+                        Some((name, value)) => e.header(name, value),
+                        None => e,
+                    })
+                    .apply(|e| {
+                        if with_md5 {
+                            e.body_with_md5(data)
+                        } else {
+                            e.body(data)
+                        }
+                    })
+                    .send_ok()
+                    .await?;
+                Ok(PutObjectResult::from_headers(
+                    bucket_name,
+                    object_name,
+                    res.headers(),
+                ))
+            }
+            #[cfg(feature = "fs-tokio")]
+            UploadSource::File(path) => {
+                use tokio::io::AsyncReadExt;
+
+                let verify_etag = key.verify_etag && key.etag_is_md5();
+                let mut file = tokio::fs::File::open(&path).await?;
+                let meta = file.metadata().await?;
+                let len = meta.len() as usize;
+                let stm = Box::pin(stream! {
+                    loop  {
+                        let mut buf = BytesMut::with_capacity(RECOMMEND_CHUNK_SIZE);
+                        let size = file.read_buf(&mut buf).await;
+                        yield match size {
+                            Ok(d) if d > 0 => Ok(buf.freeze()),
+                            Ok(_) => break,
+                            Err(e) => Err(e.into())
+                        }
+                    }
+                });
+                let result = self._upload_stream(bucket, key, stm, Some(len)).await?;
+                if verify_etag {
+                    self._verify_file_etag(&path, len, &result).await?;
+                }
+                Ok(result)
+            }
+            UploadSource::Reader(mut reader, len) => {
+                use futures::AsyncReadExt;
+
+                let stm = Box::pin(stream! {
+                    loop  {
+                        let mut buf = vec![0; RECOMMEND_CHUNK_SIZE];
+                        let size = reader.read(&mut buf).await;
+                        yield match size {
+                            Ok(d) if d > 0 => {
+                                buf.truncate(d);
+                                Ok(Bytes::from(buf))
+                            }
+                            Ok(_) => break,
+                            Err(e) => Err(e.into())
+                        }
+                    }
+                });
+                self._upload_stream(bucket, key, stm, len).await
+            }
+            UploadSource::Stream(stream, len) => {
+                self._upload_stream(bucket, key, stream, len).await
+            }
+        }
+    }
+
+    /// Compare the ETag of the uploaded object with the one computed from the file.
+    ///
+    /// A multipart ETag has the `-{parts}` suffix, the part size is the same as [Self::_upload_multipart].
+    #[cfg(feature = "fs-tokio")]
+    async fn _verify_file_etag(
+        &self,
+        path: &Path,
+        len: usize,
+        result: &PutObjectResult,
+    ) -> Result<()> {
+        use crate::utils::{compute_etag_file, compute_multipart_etag_file};
+
+        let etag = result.etag();
+        let expected = if etag.contains('-') {
+            compute_multipart_etag_file(path, self.optimal_part_size(len)?).await?
+        } else {
+            compute_etag_file(path, len).await?
+        };
+        if expected != etag {
+            return Err(Error::ChecksumMismatch(format!(
+                "ETag mismatch of {}, expected {expected}, got {etag}",
+                result.object_name()
+            )));
+        }
         Ok(())
     }
 
@@ -219,77 +1198,210 @@ impl Minio {
         &self,
         bucket: B,
         key: K,
-        mut stream: Pin<Box<dyn Stream<Item = Result<Bytes>> + Sync + Send>>,
+        stream: Pin<Box<dyn Stream<Item = Result<Bytes>> + Sync + Send>>,
         len: Option<usize>,
     ) -> Result<()>
     where
         B: Into<BucketArgs>,
         K: Into<KeyArgs>,
     {
-        let bucket: BucketArgs = bucket.into();
-        let key: KeyArgs = key.into();
+        self.upload(bucket, key, UploadSource::Stream(stream, len))
+            .await
+            .map(|_| ())
+    }
+
+    async fn _upload_stream(
+        &self,
+        bucket: BucketArgs,
+        key: KeyArgs,
+        stream: Pin<Box<dyn Stream<Item = Result<Bytes>> + Sync + Send>>,
+        len: Option<usize>,
+    ) -> Result<PutObjectResult> {
+        let trailer = match &key.trailing_checksum {
+            Some(algorithm) => {
+                #[cfg(feature = "compress")]
+                let compressed = key.compression != super::Compression::None;
+                #[cfg(not(feature = "compress"))]
+                let compressed = false;
+                if len.is_none() || compressed || !self.multi_chunked() || self.unsigned_payload() {
+                    return Err(ValueError::from(
+                        "trailing checksum requires multi_chunked_encoding, the length of the stream and no compression",
+                    )
+                    .into());
+                }
+                trailing_checksum_hasher(algorithm)?;
+                Some(algorithm.header_name())
+            }
+            None => None,
+        };
+        #[cfg(feature = "compress")]
+        if key.compression == super::Compression::Gzip {
+            return self._upload_gzip(bucket, key, stream).await;
+        }
+        let (bucket_name, object_name) = (bucket.name.clone(), key.name.clone());
         if let Some(len) = len {
-            if len >= MAX_MULTIPART_OBJECT_SIZE {
-                return Err(ValueError::from("max object size is 5TiB").into());
+            if len >= self.max_object_size() {
+                return Err(ValueError::new(format!(
+                    "max object size is {} bytes",
+                    self.max_object_size()
+                ))
+                .into());
             }
-            if self.multi_chunked() || len < MIN_PART_SIZE {
-                self._object_executor(Method::PUT, bucket, key, true, true)?
+            if self.multi_chunked() || self.unsigned_payload() || len < self.part_size() {
+                let res = self
+                    ._object_executor(Method::PUT, bucket, key, true, true)?
+                    .apply(|e| match trailer {
+                        Some(name) => e.header("x-amz-trailer", name),
+                        None => e,
+                    })
                     .body((stream, len))
                     .send_ok()
                     .await?;
-                return Ok(());
+                return Ok(PutObjectResult::from_headers(
+                    bucket_name,
+                    object_name,
+                    res.headers(),
+                ));
             }
         }
-        let mpu_args = self.create_multipart_upload(bucket, key).await?;
+        self._upload_multipart(bucket, key, stream, len).await
+    }
 
-        let mut parts = Vec::new();
-        let mut current = BytesMut::with_capacity(MIN_PART_SIZE);
-        while let Some(piece) = stream.next().await {
-            if current.len() >= MIN_PART_SIZE {
-                let part = match self
-                    .upload_part(&mpu_args, parts.len().add(1), current.freeze())
-                    .await
-                {
-                    Ok(pce) => pce,
-                    Err(e) => {
-                        return match self.abort_multipart_upload(&mpu_args).await {
-                            Ok(_) => Err(e),
-                            Err(err) => Err(err),
-                        }
-                    }
-                };
-                current = BytesMut::with_capacity(MIN_PART_SIZE);
-                parts.push(part);
+    /// Gzip the stream and upload it, the compressed content is transmitted
+    /// through `multipart_upload` if it is not smaller than the part size.
+    #[cfg(feature = "compress")]
+    async fn _upload_gzip(
+        &self,
+        bucket: BucketArgs,
+        mut key: KeyArgs,
+        stream: Pin<Box<dyn Stream<Item = Result<Bytes>> + Sync + Send>>,
+    ) -> Result<PutObjectResult> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        key.compression = super::Compression::None;
+        key.extra_headers
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header::CONTENT_ENCODING, "gzip".parse()?);
+        let part_size = self.part_size();
+        let mut stream = gzip_stream(stream);
+        let mut head = BytesMut::new();
+        while head.len() < part_size {
+            match stream.next().await {
+                Some(piece) => head.extend_from_slice(&piece?),
+                None => break,
             }
-            match piece {
-                Ok(open_piece) => {
-                    current.extend_from_slice(&open_piece);
+        }
+        if head.len() < part_size {
+            let (bucket_name, object_name) = (bucket.name.clone(), key.name.clone());
+            let compressed_size = head.len();
+            let res = self
+                ._object_executor(Method::PUT, bucket, key, true, true)?
+                .body(head.freeze())
+                .send_ok()
+                .await?;
+            let mut result = PutObjectResult::from_headers(bucket_name, object_name, res.headers());
+            result.compressed_size = Some(compressed_size);
+            return Ok(result);
+        }
+        let size = Arc::new(AtomicUsize::new(0));
+        let counter = size.clone();
+        let stream = futures::stream::iter([Ok(head.freeze())])
+            .chain(stream)
+            .inspect(move |piece| {
+                if let Ok(piece) = piece {
+                    counter.fetch_add(piece.len(), Ordering::Relaxed);
                 }
-                Err(e) => {
-                    self.abort_multipart_upload(&mpu_args).await?;
-                    return Err(e);
+            });
+        let mut result = self
+            ._upload_multipart(bucket, key, Box::pin(stream), None)
+            .await?;
+        result.compressed_size = Some(size.load(Ordering::Relaxed));
+        Ok(result)
+    }
+
+    /// upload the stream through `multipart_upload`, which is aborted if any error occurs.
+    ///
+    /// If `len` is known, the part size is enlarged to keep the parts within [MAX_MULTIPART_COUNT](crate::signer::MAX_MULTIPART_COUNT).
+    async fn _upload_multipart(
+        &self,
+        bucket: BucketArgs,
+        key: KeyArgs,
+        stream: Pin<Box<dyn Stream<Item = Result<Bytes>> + Sync + Send>>,
+        len: Option<usize>,
+    ) -> Result<PutObjectResult> {
+        let part_size = match len {
+            Some(len) => self.optimal_part_size(len)?,
+            None => self.part_size(),
+        };
+        let (bucket_name, object_name) = (bucket.name.clone(), key.name.clone());
+        let complete_header = key.if_none_match.then(|| {
+            let mut header = HeaderMap::new();
+            header.insert(header::IF_NONE_MATCH, HeaderValue::from_static("*"));
+            header
+        });
+        let mpu_args = self.create_multipart_upload(bucket, key).await?;
+        // abort the upload if the future is dropped before it completes.
+        let mut guard = MultipartUploadGuard::new(self, mpu_args.clone());
+        let res = self
+            ._upload_parts(&mpu_args, stream, part_size, complete_header)
+            .await;
+        guard.disarm();
+        let res = match res {
+            Ok(res) => res,
+            Err(e) => {
+                return match self.abort_multipart_upload(&mpu_args).await {
+                    Ok(_) => Err(e),
+                    Err(err) => Err(err),
                 }
             }
-        }
-        if current.len() != 0 {
-            let part = match self
-                .upload_part(&mpu_args, parts.len().add(1), current.freeze())
-                .await
-            {
-                Ok(pce) => pce,
-                Err(e) => {
-                    return match self.abort_multipart_upload(&mpu_args).await {
-                        Ok(_) => Err(e),
-                        Err(err) => Err(err),
+        };
+        PutObjectResult::from_complete_response(bucket_name, object_name, res).await
+    }
+
+    /// upload the stream as parts of the multipart upload, then complete it.
+    ///
+    /// The stream is cut into parts of `part_size` bytes, only the last part may be smaller.
+    ///
+    /// At most `max_inflight_parts` parts are uploaded at the same time,
+    /// the stream is not read until one of them completes.
+    async fn _upload_parts(
+        &self,
+        mpu_args: &MultipartUploadTask,
+        mut stream: Pin<Box<dyn Stream<Item = Result<Bytes>> + Sync + Send>>,
+        part_size: usize,
+        complete_header: Option<HeaderMap>,
+    ) -> Result<Response> {
+        use async_stream::stream;
+
+        let chunks = stream! {
+            let mut current = BytesMut::with_capacity(part_size);
+            while let Some(piece) = stream.next().await {
+                match piece {
+                    Ok(piece) => current.extend_from_slice(&piece),
+                    Err(e) => {
+                        yield Err(e);
+                        return;
                     }
                 }
-            };
-            parts.push(part);
-        }
-
-        self.complete_multipart_upload(&mpu_args, parts, None)
+                while current.len() >= part_size {
+                    yield Ok(current.split_to(part_size).freeze());
+                }
+            }
+            if !current.is_empty() {
+                yield Ok(current.freeze());
+            }
+        };
+        let parts: Vec<_> = chunks
+            .enumerate()
+            .map(|(i, chunk): (usize, Result<Bytes>)| async move {
+                self.upload_part(mpu_args, i.add(1), chunk?).await
+            })
+            .buffered(self.max_inflight_parts())
+            .try_collect()
+            .await?;
+        self._complete_multipart_upload(mpu_args, parts, complete_header)
             .await
-            .map(|_| ())
     }
 
     /// Uploads data from a file to an object in a bucket.
@@ -310,39 +1422,34 @@ impl Minio {
         K: Into<KeyArgs>,
         P: AsRef<Path>,
     {
-        use crate::signer::RECOMMEND_CHUNK_SIZE;
-        use async_stream::stream;
-        use tokio::io::AsyncReadExt;
-
-        let mut file = tokio::fs::File::open(path).await?;
-        let meta = file.metadata().await?;
-        let len = meta.len() as usize;
-        let stm = Box::pin(stream! {
-            loop  {
-                let mut buf = BytesMut::with_capacity(RECOMMEND_CHUNK_SIZE);
-                let size = file.read_buf(&mut buf).await;
-                yield match size {
-                    Ok(d) if d > 0 => Ok(buf.freeze()),
-                    Ok(_) => break,
-                    Err(e) => Err(e.into())
-                }
-            }
-        });
-        self.put_object_stream(bucket, key, stm, Some(len)).await
+        let source = UploadSource::File(path.as_ref().to_path_buf());
+        self.upload(bucket, key, source).await.map(|_| ())
     }
 
     /// Remove an object.
+    ///
+    /// - Set `version_id` of [KeyArgs] to permanently delete a specific version of the object,
+    ///   otherwise a delete marker is created on versioned buckets.
+    /// - Set `if_match` of [KeyArgs] to delete the object only if its ETag matches.
+    ///
+    /// The [RemoveObjectResult] tells whether a delete marker was created and its version-ID.
     /// ## Exapmle
     /// ``` rust
     /// # use sc_minio::Minio;
+    /// # use sc_minio::client::KeyArgs;
     /// # use sc_minio::error::Result;
     /// # async fn example(minio: Minio)->Result<()>{
-    /// let response = minio.remove_object("bucket", "file.txt").await?;
+    /// let result = minio.remove_object("bucket", "file.txt").await?;
+    /// if result.delete_marker() {
+    ///     println!("delete marker {:?} created", result.version_id());
+    /// }
+    /// let key = KeyArgs::new("file.txt").if_match("5eb63bbbe01eeed093cb22bb8f5acdc3");
+    /// let response = minio.remove_object("bucket", key).await?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub async fn remove_object<B, K>(&self, bucket: B, key: K) -> Result<()>
+    pub async fn remove_object<B, K>(&self, bucket: B, key: K) -> Result<RemoveObjectResult>
     where
         B: Into<BucketArgs>,
         K: Into<KeyArgs>,
@@ -350,7 +1457,94 @@ impl Minio {
         self._object_executor(Method::DELETE, bucket.into(), key.into(), true, false)?
             .send_ok()
             .await
-            .map(|_| ())
+            .map(|res| RemoveObjectResult::from_headers(res.headers()))
+    }
+
+    /// Remove multiple objects in a single request, at most 1000 objects.
+    ///
+    /// Set `version_id` of [KeyArgs] to permanently delete a specific version of the object.
+    /// Only the objects failed to be removed are returned.
+    /// ## Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let errors = minio.remove_objects("bucket", ["a.txt", "b.txt"]).await?;
+    /// for err in errors {
+    ///     println!("{}: {}", err.key, err.code);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_objects<B, K, I>(&self, bucket: B, keys: I) -> Result<Vec<DeleteError>>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+        I: IntoIterator<Item = K>,
+    {
+        let keys: Vec<KeyArgs> = keys.into_iter().map(Into::into).collect();
+        if keys.is_empty() {
+            return Ok(vec![]);
+        }
+        if keys.len() > 1000 {
+            return Err(ValueError::from("at most 1000 objects can be removed at once").into());
+        }
+        let mut body = "<Delete><Quiet>true</Quiet>".to_string();
+        for key in &keys {
+            body += "<Object><Key>";
+            body += &xml_escape(&key.name);
+            body += "</Key>";
+            if let Some(version_id) = &key.version_id {
+                body += "<VersionId>";
+                body += &xml_escape(version_id);
+                body += "</VersionId>";
+            }
+            body += "</Object>";
+        }
+        body += "</Delete>";
+        let result: DeleteResult = self
+            ._bucket_executor(bucket.into(), Method::POST)
+            .query("delete", "")
+            .body_with_md5(body.into())
+            .send_xml_ok()
+            .await?;
+        Ok(result.errors)
+    }
+
+    /// Get the raw [Response] of a `HEAD` request on an object, to inspect any header.
+    ///
+    /// return Ok(Some([Response])) if object exists and you have READ access to the object, otherwise return Ok([None])
+    /// ## Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// if let Some(response) = minio.head_object("bucket", "file.txt").await? {
+    ///     let replication = response.headers().get("x-amz-replication-status");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn head_object<B, K>(&self, bucket: B, key: K) -> Result<Option<Response>>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        let key: KeyArgs = key.into();
+        let part_number = key.part_number;
+        let res = self
+            ._object_executor(Method::HEAD, bucket.into(), key, true, false)?
+            .apply(|e| match part_number {
+                Some(part_number) => e.query("partNumber", part_number.to_string()),
+                None => e,
+            })
+            .send()
+            .await?;
+        if res.status().is_success() {
+            Ok(Some(res))
+        } else {
+            Ok(None)
+        }
     }
 
     /// Get object information.
@@ -374,13 +1568,10 @@ impl Minio {
         let key: KeyArgs = key.into();
         let bucket_name = bucket.name.clone();
         let object_name = key.name.clone();
-        let res = self
-            ._object_executor(Method::HEAD, bucket, key, true, false)?
-            .send()
-            .await?;
-        if !res.status().is_success() {
-            return Ok(None);
-        }
+        let res = match self.head_object(bucket, key).await? {
+            Some(res) => res,
+            None => return Ok(None),
+        };
         let res_header = res.headers();
         let etag = res_header
             .get(header::ETAG)
@@ -406,6 +1597,25 @@ impl Minio {
             .map(|x| x.to_str().unwrap_or(""))
             .unwrap_or("")
             .to_owned();
+        let parts_count = res_header
+            .get("x-amz-mp-parts-count")
+            .and_then(|x| x.to_str().ok()?.parse().ok());
+        let checksum = [
+            ChecksumAlgorithm::CRC32,
+            ChecksumAlgorithm::CRC32C,
+            ChecksumAlgorithm::CRC64NVME,
+            ChecksumAlgorithm::SHA1,
+            ChecksumAlgorithm::SHA256,
+        ]
+        .into_iter()
+        .find_map(|algorithm| {
+            let value = res_header.get(algorithm.header_name())?.to_str().ok()?;
+            Some((algorithm, value.to_owned()))
+        });
+        let checksum_type = res_header
+            .get("x-amz-checksum-type")
+            .and_then(|x| x.to_str().ok())
+            .map(ToOwned::to_owned);
         let mut metadata = HashMap::new();
         res_header.into_iter().for_each(|(k, v)| {
             let key = k.as_str();
@@ -423,10 +1633,49 @@ impl Minio {
             content_type,
             version_id,
             size,
+            parts_count,
+            checksum,
+            checksum_type,
             metadata,
         }))
     }
 
+    /// Get [ObjectStat] and [Tags] of an object, the two requests are sent concurrently.
+    ///
+    /// return Ok([None]) if the object does not exist, and empty tags if the object has no tags.
+    /// ## Example
+    /// ```rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// if let Some(desc) = minio.describe_object("bucket", "file.txt").await? {
+    ///     println!("{} {:?}", desc.stat().size(), desc.tags());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn describe_object<B, K>(
+        &self,
+        bucket: B,
+        key: K,
+    ) -> Result<Option<ObjectDescription>>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let key: KeyArgs = key.into();
+        let (stat, tags) = futures::join!(
+            self.stat_object(bucket.clone(), key.clone()),
+            self.get_object_tags(bucket, key)
+        );
+        let stat = match stat? {
+            Some(stat) => stat,
+            None => return Ok(None),
+        };
+        Ok(Some(ObjectDescription { stat, tags: tags? }))
+    }
+
     /// Get the access control list (ACL) of an object.
     pub async fn get_object_acl<B, K>(&self, bucket: B, key: K) -> Result<AccessControlPolicy>
     where
@@ -441,29 +1690,29 @@ impl Minio {
             .await
     }
 
-    /// Returns true if legal hold is enabled on an object.
-    pub async fn is_object_legal_hold_enabled<B, K>(&self, bucket: B, key: K) -> Result<bool>
+    /// Get [LegalHold] of an object.
+    pub async fn get_object_legal_hold<B, K>(&self, bucket: B, key: K) -> Result<LegalHold>
     where
         B: Into<BucketArgs>,
         K: Into<KeyArgs>,
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
-        let result = self
-            ._object_executor(Method::GET, bucket, key, false, false)?
+        self._object_executor(Method::GET, bucket, key, false, false)?
             .query("legal-hold", "")
-            .send_xml_ok::<LegalHold>()
-            .await;
-        match result {
+            .send_xml_ok()
+            .await
+    }
+
+    /// Returns true if legal hold is enabled on an object.
+    pub async fn is_object_legal_hold_enabled<B, K>(&self, bucket: B, key: K) -> Result<bool>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        match self.get_object_legal_hold(bucket, key).await {
             Ok(l) => Ok(l.status == LegalHoldStatus::ON),
-            // Ok(Err(err)) => Err(err.into()),
-            Err(Error::S3Error(s)) => {
-                if s.code == "NoSuchObjectLockConfiguration" {
-                    return Ok(false);
-                } else {
-                    Err(Error::S3Error(s))
-                }
-            }
+            Err(Error::S3Error(s)) if s.code == "NoSuchObjectLockConfiguration" => Ok(false),
             Err(err) => Err(err),
         }
     }
@@ -507,6 +1756,7 @@ impl Minio {
     }
 
     /// Get [Tags] of an object.
+    /// Note: return an empty [Tags] if the object has no tag set.
     /// ## Example
     /// ```rust
     /// # use sc_minio::Minio;
@@ -525,13 +1775,21 @@ impl Minio {
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
-        self._object_executor(Method::GET, bucket, key, false, false)?
+        let res = self
+            ._object_executor(Method::GET, bucket, key, false, false)?
             .query("tagging", "")
-            .send_xml_ok()
-            .await
+            .send_xml_ok::<Tags>()
+            .await;
+        match res {
+            Ok(tags) => Ok(tags),
+            Err(Error::S3Error(s)) if s.code == "NoSuchTagSet" => Ok(Tags::new()),
+            Err(err) => Err(err),
+        }
     }
 
     /// Set [Tags] of an object.
+    ///
+    /// An object can have at most 10 tags, a [ValueError] is returned if the tags exceed the limits.
     /// ## Example
     /// ```rust
     /// # use sc_minio::Minio;
@@ -554,9 +1812,11 @@ impl Minio {
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
+        let tags: Tags = tags.into();
+        tags.validate(10)?;
         self._object_executor(Method::PUT, bucket, key, false, false)?
             .query("tagging", "")
-            .xml(&tags.into())
+            .xml(&tags)
             .send_ok()
             .await
             .map(|_| ())
@@ -586,7 +1846,72 @@ impl Minio {
             .map(|_| ())
     }
 
+    /// Get the [ObjectAttributes] of an object without fetching the body,
+    /// like the ETag, checksum, size, storage class and parts.
+    ///
+    /// Only the given attributes are returned, at least one attribute is required.
+    /// Parts are listed up to 1000, set `x-amz-max-parts` and `x-amz-part-number-marker`
+    /// by [KeyArgs::extra_headers] to list more.
+    /// # Exapmle
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::error::Result;
+    /// use sc_minio::datatype::ObjectAttribute;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let attributes = minio
+    ///     .get_object_attributes(
+    ///         "bucket",
+    ///         "file.txt",
+    ///         &[ObjectAttribute::ObjectSize, ObjectAttribute::ObjectParts],
+    ///     )
+    ///     .await?;
+    /// let parts = attributes.object_parts.map(|p| p.parts_count).unwrap_or(0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_object_attributes<B, K>(
+        &self,
+        bucket: B,
+        key: K,
+        attributes: &[ObjectAttribute],
+    ) -> Result<ObjectAttributes>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        if attributes.is_empty() {
+            return Err(ValueError::from("at least one object attribute is required").into());
+        }
+        let bucket: BucketArgs = bucket.into();
+        let key: KeyArgs = key.into();
+        let attributes = attributes
+            .iter()
+            .map(ObjectAttribute::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+        let res = self
+            ._object_executor(Method::GET, bucket, key, true, false)?
+            .query("attributes", "")
+            .header("x-amz-object-attributes", attributes)
+            .send_ok()
+            .await?;
+        let header_value = |name| {
+            res.headers()
+                .get(name)
+                .and_then(|x| x.to_str().ok())
+                .map(String::from)
+        };
+        let version_id = header_value("x-amz-version-id");
+        let last_modified = header_value(header::LAST_MODIFIED.as_str());
+        let mut result = ObjectAttributes::from_xml(res.text().await?)?;
+        result.version_id = version_id;
+        result.last_modified = last_modified;
+        Ok(result)
+    }
+
     /// Get [Retention] of an object.
+    /// The `retain_until_date` is parsed to [UtcTime](crate::time::UtcTime),
+    /// use [UtcTime::datetime](crate::time::UtcTime::datetime) to get the [chrono::DateTime].
     pub async fn get_object_retention<B, K>(&self, bucket: B, key: K) -> Result<Retention>
     where
         B: Into<BucketArgs>,
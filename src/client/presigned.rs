@@ -1,11 +1,14 @@
+use std::collections::HashMap;
 use std::str::FromStr;
 
+use base64::Engine;
 use hyper::HeaderMap;
 use hyper::{Method, Uri};
+use serde_json::json;
 
-use super::{PresignedArgs, QueryMap};
+use super::{BucketArgs, PostPolicy, PresignedArgs, QueryMap};
 use crate::error::{Result, ValueError};
-use crate::signer::presign_v4;
+use crate::signer::{post_presign_v4, presign_v4};
 use crate::time::UtcTime;
 use crate::utils::urlencode_binary;
 use crate::Minio;
@@ -19,7 +22,7 @@ impl Minio {
     /// - object_name: Object name in the bucket.
     /// - expires: Expiry in seconds. between 1, 604800
     /// - response_headers Optional response_headers argument to specify response fields like date, size, type of file, data about server, etc.
-    /// - request_date: Optional request_date argument to specify a different request date. Default is current date.
+    /// - request_date: Optional request_date argument to specify a different request date. Default is the time of the client clock.
     /// - version_id: Version ID of the object.
     /// - extra_query_params: Extra query parameters for advanced usage.
     async fn _get_presigned_url<T1: Into<String>, T2: Into<String>>(
@@ -36,7 +39,15 @@ impl Minio {
         if expires < 1 || expires > 604800 {
             return Err(ValueError::from("expires must be between 1 second to 7 days").into());
         }
-        let date: UtcTime = request_date.unwrap_or(UtcTime::default());
+        let date: UtcTime = request_date.unwrap_or_else(|| self.now());
+        let expiration = date.datetime() + chrono::Duration::seconds(expires as i64);
+        if expiration <= self.now().datetime() {
+            return Err(ValueError::new(format!(
+                "the presigned URL signed at {} is already expired",
+                date.format_time()
+            ))
+            .into());
+        }
         let mut query = extra_query_params.unwrap_or(QueryMap::new());
         if let Some(id) = version_id {
             query.insert("versionId".to_string(), id);
@@ -50,13 +61,15 @@ impl Minio {
                 query.insert(name.to_string(), urlencode_binary(value.as_bytes(), false));
             }
         }
-        let uri = self._build_uri(Some(bucket_name.into()), Some(object_name.into()));
+        let bucket_name: String = bucket_name.into();
+        let region = self._get_region(Some(&bucket_name));
+        let uri = self._build_uri(Some(bucket_name), Some(object_name.into()));
         let uri = uri + "?" + &query.to_query_string();
         let uri = Uri::from_str(&uri).map_err(|e| ValueError::new(e.to_string()))?;
         let r = presign_v4(
             &method,
             &uri,
-            self.region(),
+            &region,
             credentials.access_key(),
             credentials.secret_key(),
             &date,
@@ -124,4 +137,79 @@ impl Minio {
         )
         .await
     }
+
+    /// Get the form action URL and the signed form fields of a POST policy,
+    /// to let browsers upload objects with HTML forms.
+    ///
+    /// The file must be the last field of the form.
+    /// ## Example
+    /// ``` rust
+    /// # use sc_minio::Minio;
+    /// # use sc_minio::client::PostPolicy;
+    /// # async fn example(minio: Minio){
+    /// let policy = PostPolicy::new()
+    ///     .key_starts_with("uploads/")
+    ///     .content_length_range(1, 10 * 1024 * 1024);
+    /// let (url, fields) = minio.presigned_post_policy("bucket", policy).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn presigned_post_policy<B: Into<BucketArgs>>(
+        &self,
+        bucket: B,
+        policy: PostPolicy,
+    ) -> Result<(String, HashMap<String, String>)> {
+        let bucket: BucketArgs = bucket.into();
+        if !(1..=604800).contains(&policy.expires) {
+            return Err(ValueError::from("expires must be between 1 second to 7 days").into());
+        }
+        if !policy.conditions.iter().any(|(_, field, _)| field == "key") {
+            return Err(ValueError::from("the key condition of the policy must be set").into());
+        }
+        let region = bucket
+            .region
+            .clone()
+            .unwrap_or_else(|| self._get_region(Some(&bucket.name)));
+        let date = self.now();
+        let expiration = date.datetime() + chrono::Duration::seconds(policy.expires as i64);
+        let credentials = self.fetch_credentials().await;
+
+        let mut fields = HashMap::new();
+        let mut conditions = vec![json!(["eq", "$bucket", bucket.name])];
+        for (op, field, value) in policy.conditions {
+            conditions.push(json!([op, format!("${field}"), value]));
+            if op == "eq" {
+                fields.insert(field, value);
+            }
+        }
+        if let Some((min, max)) = policy.content_length_range {
+            conditions.push(json!(["content-length-range", min, max]));
+        }
+        let credential = format!(
+            "{}/{}/{}/s3/aws4_request",
+            credentials.access_key(),
+            date.aws_format_date(),
+            region
+        );
+        let mut amz_fields = vec![
+            ("x-amz-algorithm", "AWS4-HMAC-SHA256".to_string()),
+            ("x-amz-credential", credential),
+            ("x-amz-date", date.aws_format_time()),
+        ];
+        if let Some(token) = credentials.session_token() {
+            amz_fields.push(("x-amz-security-token", token.to_string()));
+        }
+        for (field, value) in amz_fields {
+            conditions.push(json!(["eq", format!("${field}"), value]));
+            fields.insert(field.to_string(), value);
+        }
+        let policy = json!({
+            "expiration": UtcTime::new(expiration).format_time(),
+            "conditions": conditions,
+        });
+        let policy = base64::engine::general_purpose::STANDARD.encode(policy.to_string());
+        let signature = post_presign_v4(&policy, credentials.secret_key(), &date, &region);
+        fields.insert("policy".to_string(), policy);
+        fields.insert("x-amz-signature".to_string(), signature);
+        Ok((self._build_uri(Some(bucket.name), None), fields))
+    }
 }
@@ -40,10 +40,12 @@ impl QueryMap {
 
     /// get query string.
     /// the empty keys will be skipped.
-    /// key and value will be uri encode.
+    /// key and value will be uri encode, then sorted by key and value,
+    /// which is the order of the canonical query string of signatures.
     #[inline]
     pub fn to_query_string(self) -> String {
-        self.0
+        let mut querys = self
+            .0
             .iter()
             .filter(|(k, _)| !k.is_empty())
             .map(|(k, v)| {
@@ -57,12 +59,13 @@ impl QueryMap {
                 } else {
                     v.to_owned()
                 };
-                if v.is_empty() {
-                    k
-                } else {
-                    format!("{k}={v}")
-                }
+                (k, v)
             })
+            .collect::<Vec<_>>();
+        querys.sort();
+        querys
+            .into_iter()
+            .map(|(k, v)| if v.is_empty() { k } else { format!("{k}={v}") })
             .collect::<Vec<String>>()
             .join("&")
     }
@@ -73,3 +76,24 @@ impl Into<String> for QueryMap {
         self.to_query_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::QueryMap;
+
+    #[test]
+    fn test_query_string() {
+        let mut query = QueryMap::from_str("prefix=dir/&uploads");
+        query.insert("Zeta".to_string(), "a b".to_string());
+        query.insert("a.".to_string(), "1".to_string());
+        let mut other = QueryMap::new();
+        other.insert("a/".to_string(), "2".to_string());
+        other.insert("".to_string(), "skipped".to_string());
+        query.merge(other);
+        let query: String = query.into();
+        assert_eq!(query, "Zeta=a%20b&a%2F=2&a.=1&prefix=dir%2F&uploads");
+        // already encoded values are kept.
+        let query = QueryMap::from_str("continuation-token=a%2Bb");
+        assert_eq!(query.to_query_string(), "continuation-token=a%2Bb");
+    }
+}
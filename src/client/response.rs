@@ -1,5 +1,15 @@
 use std::collections::HashMap;
 
+use bytes::Bytes;
+use futures::{Stream, TryStreamExt};
+use hyper::{header, HeaderMap, StatusCode};
+use reqwest::Response;
+
+use super::Tags;
+use crate::datatype::{ChecksumAlgorithm, CompleteMultipartUploadResult, FromXml};
+use crate::error::{Error, Result};
+use crate::time::UtcTime;
+
 #[derive(Debug, Clone)]
 pub struct ObjectStat {
     pub(crate) bucket_name: String,
@@ -9,10 +19,35 @@ pub struct ObjectStat {
     pub(crate) content_type: String,
     pub(crate) version_id: String,
     pub(crate) size: usize,
+    pub(crate) parts_count: Option<u32>,
+    pub(crate) checksum: Option<(ChecksumAlgorithm, String)>,
+    pub(crate) checksum_type: Option<String>,
     pub(crate) metadata: HashMap<String, String>,
 }
 
 impl ObjectStat {
+    /// Create an [ObjectStat] without metadata, e.g. returned by a fake object store in tests.
+    pub fn new<B, O, E>(bucket_name: B, object_name: O, etag: E, size: usize) -> Self
+    where
+        B: Into<String>,
+        O: Into<String>,
+        E: Into<String>,
+    {
+        Self {
+            bucket_name: bucket_name.into(),
+            object_name: object_name.into(),
+            last_modified: String::new(),
+            etag: etag.into(),
+            content_type: String::new(),
+            version_id: String::new(),
+            size,
+            parts_count: None,
+            checksum: None,
+            checksum_type: None,
+            metadata: HashMap::new(),
+        }
+    }
+
     pub fn bucket_name(&self) -> &str {
         self.bucket_name.as_ref()
     }
@@ -41,7 +76,299 @@ impl ObjectStat {
         self.size
     }
 
+    /// Number of parts of a multipart object, returned by the S3 server only if
+    /// [KeyArgs::part_number](super::KeyArgs::part_number) is set.
+    pub fn parts_count(&self) -> Option<u32> {
+        self.parts_count
+    }
+
+    /// Base64-encoded checksum of the object and its algorithm, returned by the S3 server only if
+    /// [KeyArgs::checksum_mode](super::KeyArgs::checksum_mode) is set.
+    pub fn checksum(&self) -> Option<(&ChecksumAlgorithm, &str)> {
+        self.checksum.as_ref().map(|(a, v)| (a, v.as_str()))
+    }
+
+    /// Type of the [checksum](Self::checksum), `FULL_OBJECT` or `COMPOSITE`.
+    pub fn checksum_type(&self) -> Option<&str> {
+        self.checksum_type.as_deref()
+    }
+
+    /// return the checksum if it is computed over the whole object.
+    ///
+    /// A checksum without type is a full-object checksum, unless it has the `-{parts}` suffix of composite checksums.
+    #[cfg(feature = "fs-tokio")]
+    pub(crate) fn full_object_checksum(&self) -> Option<(&ChecksumAlgorithm, &str)> {
+        let (algorithm, value) = self.checksum()?;
+        match self.checksum_type() {
+            Some("FULL_OBJECT") => Some((algorithm, value)),
+            None if !value.contains('-') => Some((algorithm, value)),
+            _ => None,
+        }
+    }
+
     pub fn metadata(&self) -> &HashMap<String, String> {
         &self.metadata
     }
 }
+
+/// Details of an object returned by `describe_object`, the [ObjectStat] and [Tags] of the object.
+#[derive(Debug, Clone)]
+pub struct ObjectDescription {
+    pub(crate) stat: ObjectStat,
+    pub(crate) tags: Tags,
+}
+
+impl ObjectDescription {
+    pub fn stat(&self) -> &ObjectStat {
+        &self.stat
+    }
+
+    /// Tags of the object, empty if the object has no tags.
+    pub fn tags(&self) -> &Tags {
+        &self.tags
+    }
+
+    /// User-defined metadata of the object.
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        self.stat.metadata()
+    }
+
+    pub fn into_parts(self) -> (ObjectStat, Tags) {
+        (self.stat, self.tags)
+    }
+}
+
+/// Response of `get_object`, the status and headers can be inspected before reading the body.
+#[derive(Debug)]
+pub struct GetObjectResponse {
+    response: Response,
+}
+
+impl GetObjectResponse {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.response
+            .headers()
+            .get(name)
+            .and_then(|x| x.to_str().ok())
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.response.status()
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        self.response.headers()
+    }
+
+    /// ETag of the object, without quotes.
+    pub fn etag(&self) -> Option<&str> {
+        self.header("etag").map(|x| x.trim_matches('"'))
+    }
+
+    /// Version-ID of the object, only returned if the bucket has versioning enabled.
+    pub fn version_id(&self) -> Option<&str> {
+        self.header("x-amz-version-id")
+    }
+
+    /// Byte length of the body, which is the length of the range if a range is requested.
+    pub fn content_length(&self) -> Option<u64> {
+        self.header("content-length").and_then(|x| x.parse().ok())
+    }
+
+    pub fn content_type(&self) -> Option<&str> {
+        self.header("content-type")
+    }
+
+    /// Server-side encryption algorithm of the object, `AES256` or `aws:kms`,
+    /// not returned for objects encrypted with SSE-C.
+    pub fn server_side_encryption(&self) -> Option<&str> {
+        self.header("x-amz-server-side-encryption")
+    }
+
+    /// Algorithm of the SSE-C customer key, `AES256`, only returned for objects encrypted with SSE-C.
+    pub fn sse_customer_algorithm(&self) -> Option<&str> {
+        self.header("x-amz-server-side-encryption-customer-algorithm")
+    }
+
+    /// Base64-encoded MD5 of the SSE-C customer key, only returned for objects encrypted with SSE-C.
+    pub fn sse_customer_key_md5(&self) -> Option<&str> {
+        self.header("x-amz-server-side-encryption-customer-key-md5")
+    }
+
+    /// ID of the KMS key, only returned for objects encrypted with SSE-KMS.
+    pub fn sse_kms_key_id(&self) -> Option<&str> {
+        self.header("x-amz-server-side-encryption-aws-kms-key-id")
+    }
+
+    pub fn last_modified(&self) -> Option<UtcTime> {
+        self.header("last-modified")
+            .and_then(|x| chrono::DateTime::parse_from_rfc2822(x).ok())
+            .map(|x| UtcTime::new(x.to_utc()))
+    }
+
+    /// Get the body as a stream of [Bytes].
+    pub fn into_stream(self) -> impl Stream<Item = Result<Bytes>> {
+        self.response.bytes_stream().map_err(Error::from)
+    }
+
+    pub async fn bytes(self) -> Result<Bytes> {
+        Ok(self.response.bytes().await?)
+    }
+
+    pub async fn text(self) -> Result<String> {
+        Ok(self.response.text().await?)
+    }
+
+    /// Get the underlying [Response].
+    pub fn into_inner(self) -> Response {
+        self.response
+    }
+}
+
+impl From<Response> for GetObjectResponse {
+    fn from(response: Response) -> Self {
+        Self { response }
+    }
+}
+
+/// Result of uploading an object.
+#[derive(Debug, Clone)]
+pub struct PutObjectResult {
+    pub(crate) bucket_name: String,
+    pub(crate) object_name: String,
+    pub(crate) etag: String,
+    pub(crate) version_id: Option<String>,
+    #[cfg(feature = "compress")]
+    pub(crate) compressed_size: Option<usize>,
+}
+
+impl PutObjectResult {
+    pub(crate) fn from_headers(
+        bucket_name: String,
+        object_name: String,
+        headers: &HeaderMap,
+    ) -> Self {
+        let etag = headers
+            .get(header::ETAG)
+            .and_then(|x| x.to_str().ok())
+            .unwrap_or("")
+            .replace('"', "");
+        let version_id = headers
+            .get("x-amz-version-id")
+            .and_then(|x| x.to_str().ok())
+            .map(String::from);
+        Self {
+            bucket_name,
+            object_name,
+            etag,
+            version_id,
+            #[cfg(feature = "compress")]
+            compressed_size: None,
+        }
+    }
+
+    /// Read the result from the response of `complete_multipart_upload`.
+    pub(crate) async fn from_complete_response(
+        bucket_name: String,
+        object_name: String,
+        res: Response,
+    ) -> Result<Self> {
+        let version_id = res
+            .headers()
+            .get("x-amz-version-id")
+            .and_then(|x| x.to_str().ok())
+            .map(String::from);
+        let result = CompleteMultipartUploadResult::from_xml(res.text().await?)?;
+        Ok(Self {
+            bucket_name,
+            object_name,
+            etag: result.e_tag.replace('"', ""),
+            version_id,
+            #[cfg(feature = "compress")]
+            compressed_size: None,
+        })
+    }
+
+    pub fn bucket_name(&self) -> &str {
+        self.bucket_name.as_ref()
+    }
+
+    pub fn object_name(&self) -> &str {
+        self.object_name.as_ref()
+    }
+
+    pub fn etag(&self) -> &str {
+        self.etag.as_ref()
+    }
+
+    /// Version-ID of the object, only returned if the bucket has versioning enabled.
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+
+    /// Byte length of the compressed content, only returned if [KeyArgs::compress](super::KeyArgs::compress) is set.
+    #[cfg(feature = "compress")]
+    pub fn compressed_size(&self) -> Option<usize> {
+        self.compressed_size
+    }
+}
+
+/// Result of an operation applied to each object under a prefix.
+#[cfg(feature = "ext")]
+#[derive(Debug, Default)]
+pub struct BulkResult {
+    pub(crate) succeeded: Vec<String>,
+    pub(crate) failed: Vec<(String, Error)>,
+}
+
+#[cfg(feature = "ext")]
+impl BulkResult {
+    /// Keys of the objects on which the operation succeeded.
+    pub fn succeeded(&self) -> &[String] {
+        &self.succeeded
+    }
+
+    /// Keys of the objects on which the operation failed, with the error.
+    pub fn failed(&self) -> &[(String, Error)] {
+        &self.failed
+    }
+
+    /// Whether the operation succeeded on all objects.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Result of removing an object.
+#[derive(Debug, Clone)]
+pub struct RemoveObjectResult {
+    pub(crate) delete_marker: bool,
+    pub(crate) version_id: Option<String>,
+}
+
+impl RemoveObjectResult {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        let delete_marker = headers
+            .get("x-amz-delete-marker")
+            .map(|x| x == "true")
+            .unwrap_or(false);
+        let version_id = headers
+            .get("x-amz-version-id")
+            .and_then(|x| x.to_str().ok())
+            .map(String::from);
+        Self {
+            delete_marker,
+            version_id,
+        }
+    }
+
+    /// Whether a delete marker was created or removed on a versioned bucket.
+    pub fn delete_marker(&self) -> bool {
+        self.delete_marker
+    }
+
+    /// Version-ID of the delete marker created, or of the version removed.
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+}
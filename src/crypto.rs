@@ -0,0 +1,282 @@
+//! Pluggable hash functions used by signatures and integrity headers.
+//!
+//! The built-in implementations can be replaced by a FIPS-validated backend,
+//! by installing a [CryptoProvider] before the first request.
+//!
+//! With the `fips` feature, MD5 is not used by the built-in provider. The `Content-MD5` header
+//! of requests like tagging and bucket policy is replaced by `x-amz-checksum-sha256`,
+//! and SSE-C keys, which require MD5 by the S3 protocol, are not available.
+//!
+//! The `x-amz-checksum-sha1` and `x-amz-checksum-sha256` checksums are also computed by the provider,
+//! through the incremental hashes of [sha1_context](CryptoProvider::sha1_context) and
+//! [sha256_context](CryptoProvider::sha256_context).
+//!
+//! ## Example
+//! ```rust
+//! use sc_minio::crypto::{install_provider, CryptoProvider, DefaultCryptoProvider};
+//!
+//! struct FipsProvider;
+//!
+//! impl CryptoProvider for FipsProvider {
+//!     fn sha256(&self, data: &[u8]) -> [u8; 32] {
+//!         // call the validated module here.
+//!         DefaultCryptoProvider.sha256(data)
+//!     }
+//!
+//!     fn hmac_sha256(&self, key: &[u8], data: &[u8]) -> [u8; 32] {
+//!         DefaultCryptoProvider.hmac_sha256(key, data)
+//!     }
+//!
+//!     fn md5(&self, _data: &[u8]) -> Option<[u8; 16]> {
+//!         None
+//!     }
+//! }
+//!
+//! install_provider(FipsProvider).unwrap();
+//! ```
+use hmac::{Hmac, Mac};
+use once_cell::sync::OnceCell;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use crate::error::ValueError;
+
+/// An incremental hash, created by the `*_context` methods of [CryptoProvider].
+pub trait HashContext: Send {
+    /// Feed data to the hash.
+    fn update(&mut self, data: &[u8]);
+
+    /// Return the digest of all the data fed.
+    fn finalize(self: Box<Self>) -> Vec<u8>;
+}
+
+/// define the hash functions used by the client.
+pub trait CryptoProvider: Send + Sync {
+    /// Return the SHA-256 digest of data.
+    fn sha256(&self, data: &[u8]) -> [u8; 32];
+
+    /// Return the HMAC-SHA256 digest of given key and data.
+    fn hmac_sha256(&self, key: &[u8], data: &[u8]) -> [u8; 32];
+
+    /// Return the MD5 digest of data, [None] if MD5 is not available.
+    fn md5(&self, data: &[u8]) -> Option<[u8; 16]>;
+
+    /// Return an incremental MD5 hash, [None] if MD5 is not available.
+    ///
+    /// The default buffers the data and calls [md5](Self::md5) when finalized.
+    fn md5_context(&self) -> Option<Box<dyn HashContext + '_>> {
+        self.md5(&[])?;
+        Some(Box::new(BufferedContext::new(move |data| {
+            self.md5(data).unwrap_or_default().to_vec()
+        })))
+    }
+
+    /// Return the SHA-1 digest of data, [None] if SHA-1 is not available.
+    ///
+    /// Default: [None], the `x-amz-checksum-sha1` checksum is not available.
+    fn sha1(&self, _data: &[u8]) -> Option<[u8; 20]> {
+        None
+    }
+
+    /// Return an incremental SHA-256 hash.
+    ///
+    /// The default buffers the data and calls [sha256](Self::sha256) when finalized,
+    /// override it to hash large objects without holding them in memory.
+    fn sha256_context(&self) -> Box<dyn HashContext + '_> {
+        Box::new(BufferedContext::new(move |data| self.sha256(data).to_vec()))
+    }
+
+    /// Return an incremental SHA-1 hash, [None] if SHA-1 is not available.
+    ///
+    /// The default buffers the data and calls [sha1](Self::sha1) when finalized.
+    fn sha1_context(&self) -> Option<Box<dyn HashContext + '_>> {
+        self.sha1(&[])?;
+        Some(Box::new(BufferedContext::new(move |data| {
+            self.sha1(data).unwrap_or_default().to_vec()
+        })))
+    }
+}
+
+/// A [HashContext] which buffers all the data and hashes it at once.
+struct BufferedContext<F> {
+    data: Vec<u8>,
+    digest: F,
+}
+
+impl<F: Fn(&[u8]) -> Vec<u8> + Send> BufferedContext<F> {
+    fn new(digest: F) -> Self {
+        Self {
+            data: vec![],
+            digest,
+        }
+    }
+}
+
+impl<F: Fn(&[u8]) -> Vec<u8> + Send> HashContext for BufferedContext<F> {
+    fn update(&mut self, data: &[u8]) {
+        self.data.extend_from_slice(data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        (self.digest)(&self.data)
+    }
+}
+
+#[cfg(not(feature = "fips"))]
+impl HashContext for md5::Context {
+    fn update(&mut self, data: &[u8]) {
+        self.consume(data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        self.compute().0.to_vec()
+    }
+}
+
+/// A [HashContext] of the RustCrypto hashes.
+struct DigestContext<D>(D);
+
+impl<D: Digest + Send> HashContext for DigestContext<D> {
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(&mut self.0, data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        self.0.finalize().to_vec()
+    }
+}
+
+/// The built-in [CryptoProvider] based on the RustCrypto crates.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultCryptoProvider;
+
+impl CryptoProvider for DefaultCryptoProvider {
+    fn sha256(&self, data: &[u8]) -> [u8; 32] {
+        Sha256::digest(data).into()
+    }
+
+    fn hmac_sha256(&self, key: &[u8], data: &[u8]) -> [u8; 32] {
+        let mut hasher = Hmac::<Sha256>::new_from_slice(key).expect("");
+        hasher.update(data);
+        hasher.finalize().into_bytes().into()
+    }
+
+    #[cfg(not(feature = "fips"))]
+    fn md5(&self, data: &[u8]) -> Option<[u8; 16]> {
+        Some(md5::compute(data).0)
+    }
+
+    #[cfg(feature = "fips")]
+    fn md5(&self, _data: &[u8]) -> Option<[u8; 16]> {
+        None
+    }
+
+    #[cfg(not(feature = "fips"))]
+    fn md5_context(&self) -> Option<Box<dyn HashContext + '_>> {
+        Some(Box::new(md5::Context::new()))
+    }
+
+    #[cfg(feature = "fips")]
+    fn md5_context(&self) -> Option<Box<dyn HashContext + '_>> {
+        None
+    }
+
+    fn sha1(&self, data: &[u8]) -> Option<[u8; 20]> {
+        Some(Sha1::digest(data).into())
+    }
+
+    fn sha256_context(&self) -> Box<dyn HashContext + '_> {
+        Box::new(DigestContext(Sha256::new()))
+    }
+
+    fn sha1_context(&self) -> Option<Box<dyn HashContext + '_>> {
+        Some(Box::new(DigestContext(Sha1::new())))
+    }
+}
+
+static PROVIDER: OnceCell<Box<dyn CryptoProvider>> = OnceCell::new();
+
+/// Install the [CryptoProvider] used by all clients of the process.
+///
+/// Return a [ValueError] if a provider is already installed,
+/// or the [DefaultCryptoProvider] has been used by a previous request.
+pub fn install_provider<P: CryptoProvider + 'static>(provider: P) -> Result<(), ValueError> {
+    PROVIDER
+        .set(Box::new(provider))
+        .map_err(|_| ValueError::from("a crypto provider is already in use"))
+}
+
+/// Return the installed [CryptoProvider], the [DefaultCryptoProvider] if none.
+pub(crate) fn provider() -> &'static dyn CryptoProvider {
+    PROVIDER
+        .get_or_init(|| Box::new(DefaultCryptoProvider))
+        .as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CryptoProvider, DefaultCryptoProvider, HashContext};
+
+    #[test]
+    fn test_default_provider() {
+        let provider = DefaultCryptoProvider;
+        assert_eq!(
+            hex::encode(provider.sha256(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex::encode(
+                provider.hmac_sha256(b"key", b"The quick brown fox jumps over the lazy dog")
+            ),
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+        #[cfg(not(feature = "fips"))]
+        assert_eq!(
+            hex::encode(provider.md5(b"hello world").unwrap()),
+            "5eb63bbbe01eeed093cb22bb8f5acdc3"
+        );
+        assert_eq!(
+            hex::encode(provider.sha1(b"abc").unwrap()),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+    }
+
+    #[test]
+    fn test_context() {
+        struct OneShotProvider;
+
+        impl CryptoProvider for OneShotProvider {
+            fn sha256(&self, data: &[u8]) -> [u8; 32] {
+                DefaultCryptoProvider.sha256(data)
+            }
+
+            fn hmac_sha256(&self, key: &[u8], data: &[u8]) -> [u8; 32] {
+                DefaultCryptoProvider.hmac_sha256(key, data)
+            }
+
+            fn md5(&self, _data: &[u8]) -> Option<[u8; 16]> {
+                None
+            }
+        }
+
+        let digest = |mut context: Box<dyn HashContext + '_>| {
+            context.update(b"hello ");
+            context.update(b"minio");
+            hex::encode(context.finalize())
+        };
+        let expected = hex::encode(DefaultCryptoProvider.sha256(b"hello minio"));
+        assert_eq!(digest(DefaultCryptoProvider.sha256_context()), expected);
+        assert_eq!(digest(OneShotProvider.sha256_context()), expected);
+        assert_eq!(
+            digest(DefaultCryptoProvider.sha1_context().unwrap()),
+            hex::encode(DefaultCryptoProvider.sha1(b"hello minio").unwrap())
+        );
+        assert!(OneShotProvider.sha1_context().is_none());
+        assert!(OneShotProvider.md5_context().is_none());
+        #[cfg(not(feature = "fips"))]
+        assert_eq!(
+            digest(DefaultCryptoProvider.md5_context().unwrap()),
+            "1738ebfeeab21fef70b0622d63af59d3"
+        );
+    }
+}
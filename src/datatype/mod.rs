@@ -1,7 +1,11 @@
 //! Data types
 
+mod notification;
+mod policy;
 mod select_object_content;
 
+pub use notification::*;
+pub use policy::*;
 pub use select_object_content::*;
 
 use serde::{Deserialize, Serialize};
@@ -40,6 +44,15 @@ impl_xmlself!(
     LocationConstraint
     PublicAccessBlockConfiguration
     AccessControlPolicy
+    OwnershipControls
+    AccelerateConfiguration
+    RequestPaymentConfiguration
+    BucketLoggingStatus
+    WebsiteConfiguration
+    InventoryConfiguration
+    ListInventoryConfigurationsResult
+    DeleteResult
+    ObjectAttributes
 );
 
 pub trait ToXml {
@@ -77,6 +90,17 @@ impl Region {
     }
 }
 
+/// Configures the transfer acceleration state for an Amazon S3 bucket.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccelerateConfiguration {
+    /// Specifies the transfer acceleration status of the bucket.
+    /// This element is not returned if the bucket has never been configured.
+    ///
+    /// Valid Values: Enabled | Suspended
+    pub status: Option<BucketAccelerateStatus>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct AccessControlList {
@@ -99,7 +123,18 @@ pub struct Bucket {
     /// The name of the bucket.
     pub name: String,
     /// Date the bucket was created. This date can change when making changes to your bucket, such as editing its bucket policy.
-    pub creation_date: String,
+    #[serde(deserialize_with = "crate::time::deserialize_with_str")]
+    pub creation_date: UtcTime,
+    /// The region of the bucket, only returned by some S3 services.
+    pub bucket_region: Option<String>,
+}
+
+/// Container for logging status information.
+/// Set with an empty [BucketLoggingStatus] to disable logging.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct BucketLoggingStatus {
+    pub logging_enabled: Option<LoggingEnabled>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -181,6 +216,21 @@ pub struct DefaultRetention {
     pub years: Option<usize>,
 }
 
+/// Information about an object that could not be deleted by `remove_objects`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteError {
+    /// The error code, such as `AccessDenied`.
+    pub code: String,
+    /// The key of the object.
+    pub key: String,
+    /// The error message.
+    #[serde(default)]
+    pub message: String,
+    /// Version ID of the object.
+    pub version_id: Option<String>,
+}
+
 /// Information about the delete marker.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
@@ -197,6 +247,39 @@ pub struct DeleteMarkerEntry {
     pub version_id: Option<String>,
 }
 
+/// Information about an object deleted by `remove_objects`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeletedObject {
+    /// The key of the object.
+    pub key: String,
+    /// Version ID of the object.
+    pub version_id: Option<String>,
+    /// Whether a delete marker was created or deleted.
+    #[serde(default)]
+    pub delete_marker: bool,
+    /// Version ID of the delete marker.
+    pub delete_marker_version_id: Option<String>,
+}
+
+/// The result of deleting multiple objects.
+#[derive(Clone, Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteResult {
+    #[serde(rename = "Deleted", default)]
+    pub deleted: Vec<DeletedObject>,
+    #[serde(rename = "Error", default)]
+    pub errors: Vec<DeleteError>,
+}
+
+/// The error information of the website configuration.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ErrorDocument {
+    /// The object key name to use when a 4XX class error occurs.
+    pub key: String,
+}
+
 /// Container for grant information.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
@@ -225,6 +308,85 @@ pub(crate) struct InitiateMultipartUploadResult {
     pub upload_id: String,
 }
 
+/// Container for the `Suffix` element of the website configuration.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct IndexDocument {
+    /// A suffix that is appended to a request that is for a directory on the website endpoint.
+    pub suffix: String,
+}
+
+/// Specifies the inventory configuration for an Amazon S3 bucket.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InventoryConfiguration {
+    /// Contains information about where to publish the inventory results.
+    pub destination: InventoryDestination,
+    /// Specifies an inventory filter.
+    /// The inventory only includes objects that meet the filter's criteria.
+    pub filter: Option<InventoryFilter>,
+    /// The ID used to identify the inventory configuration.
+    pub id: String,
+    /// Object versions to include in the inventory list.
+    pub included_object_versions: InventoryIncludedObjectVersions,
+    /// Specifies whether the inventory is enabled or disabled.
+    pub is_enabled: bool,
+    /// Contains the optional fields that are included in the inventory results.
+    pub optional_fields: Option<InventoryOptionalFields>,
+    /// Specifies the schedule for generating inventory results.
+    pub schedule: InventorySchedule,
+}
+
+/// Specifies the inventory configuration for an Amazon S3 bucket.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InventoryDestination {
+    /// Contains the bucket name, file format, bucket owner (optional),
+    /// and prefix (optional) where inventory results are published.
+    pub s3_bucket_destination: InventoryS3BucketDestination,
+}
+
+/// Specifies an inventory filter.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct InventoryFilter {
+    /// The prefix that an object must have to be included in the inventory results.
+    pub prefix: String,
+}
+
+/// Contains the optional fields that are included in the inventory results.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct InventoryOptionalFields {
+    /// Valid Values: `Size | LastModifiedDate | StorageClass | ETag | IsMultipartUploaded | ReplicationStatus | EncryptionStatus | ObjectLockRetainUntilDate | ObjectLockMode | ObjectLockLegalHoldStatus | IntelligentTieringAccessTier | BucketKeyStatus | ChecksumAlgorithm`
+    #[serde(rename = "Field", default)]
+    pub fields: Vec<String>,
+}
+
+/// Contains the bucket name, file format, bucket owner (optional),
+/// and prefix (optional) where inventory results are published.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InventoryS3BucketDestination {
+    /// The account ID that owns the destination S3 bucket.
+    pub account_id: Option<String>,
+    /// The Amazon Resource Name (ARN) of the bucket where inventory results will be published,
+    /// e.g. `arn:aws:s3:::bucket`.
+    pub bucket: String,
+    /// Specifies the output format of the inventory results.
+    pub format: InventoryFormat,
+    /// The prefix that is prepended to all inventory results.
+    pub prefix: Option<String>,
+}
+
+/// Specifies the schedule for generating inventory results.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InventorySchedule {
+    /// Specifies how frequently inventory results are produced.
+    pub frequency: InventoryFrequency,
+}
+
 /// Container element that identifies who initiated the multipart upload.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
@@ -249,6 +411,18 @@ pub struct ListAllMyBucketsResult {
     pub owner: Owner,
 }
 
+/// The result of list inventory configurations.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListInventoryConfigurationsResult {
+    pub continuation_token: Option<String>,
+    #[serde(rename = "InventoryConfiguration", default)]
+    pub inventory_configurations: Vec<InventoryConfiguration>,
+    #[serde(default)]
+    pub is_truncated: bool,
+    pub next_continuation_token: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ListBucketResult {
@@ -270,6 +444,37 @@ pub struct ListBucketResult {
     pub continuation_token: String,
 }
 
+impl ListBucketResult {
+    /// Get the common prefixes, the keys between the prefix and the first occurrence of the delimiter
+    /// are rolled up into a single prefix, which are not returned in the contents.
+    pub fn prefixes(&self) -> Vec<&str> {
+        self.common_prefixes
+            .iter()
+            .map(|p| p.prefix.as_str())
+            .collect()
+    }
+
+    /// Get the directories directly under the prefix, listed with the delimiter `/`.
+    ///
+    /// Includes the common prefixes and the directory markers created by `create_object_prefix`.
+    pub fn directories(&self) -> Vec<&str> {
+        let mut dirs: Vec<&str> = self
+            .common_prefixes
+            .iter()
+            .map(|p| p.prefix.as_str())
+            .chain(
+                self.contents
+                    .iter()
+                    .filter(|o| o.is_dir() && o.key != self.prefix)
+                    .map(|o| o.key.as_str()),
+            )
+            .collect();
+        dirs.sort_unstable();
+        dirs.dedup();
+        dirs
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ListMultipartUploadsResult {
@@ -356,6 +561,18 @@ pub struct LocationConstraint {
     pub location_constraint: String,
 }
 
+/// Describes where logs are stored and the prefix that Amazon S3 assigns to all log object keys for a bucket.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LoggingEnabled {
+    /// Specifies the bucket where you want Amazon S3 to store server access logs.
+    pub target_bucket: String,
+    /// A prefix for all log object keys.
+    pub target_prefix: String,
+    /// Container for granting information.
+    pub target_grants: Option<TargetGrants>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct MultipartUpload {
@@ -370,14 +587,105 @@ pub struct MultipartUpload {
 #[serde(rename_all = "PascalCase")]
 pub struct Object {
     pub key: String,
-    pub last_modified: String,
+    #[serde(deserialize_with = "crate::time::deserialize_with_str")]
+    pub last_modified: UtcTime,
     pub e_tag: String,
     pub size: u64,
+    /// The class of storage used to store the object, like `STANDARD` and `GLACIER`.
+    #[serde(default)]
     pub storage_class: String,
+    /// The owner of the object, only returned if `fetch_owner` of [ListObjectsArgs](crate::client::ListObjectsArgs) is set.
     pub owner: Option<Owner>,
     pub checksum_algorithm: Option<String>,
 }
 
+impl Object {
+    /// ETag of the object, without quotes.
+    pub fn etag(&self) -> &str {
+        self.e_tag.trim_matches('"')
+    }
+
+    /// Whether the object is a directory marker, a zero-byte object whose key ends with `/`.
+    pub fn is_dir(&self) -> bool {
+        self.size == 0 && self.key.ends_with('/')
+    }
+}
+
+/// Object representation of response XML of `get_object_attributes` API.
+///
+/// Only the attributes requested by [ObjectAttribute] are returned.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectAttributes {
+    pub e_tag: Option<String>,
+    pub checksum: Option<ObjectChecksum>,
+    pub object_parts: Option<ObjectParts>,
+    pub storage_class: Option<String>,
+    pub object_size: Option<usize>,
+    /// The version ID of the object, from the `x-amz-version-id` header.
+    #[serde(skip)]
+    pub version_id: Option<String>,
+    /// The `Last-Modified` header of the object.
+    #[serde(skip)]
+    pub last_modified: Option<String>,
+}
+
+/// The checksum of an object or a part, only the algorithm used when uploading is returned.
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectChecksum {
+    #[serde(rename = "ChecksumCRC32")]
+    pub checksum_crc32: Option<String>,
+    #[serde(rename = "ChecksumCRC32C")]
+    pub checksum_crc32c: Option<String>,
+    #[serde(rename = "ChecksumCRC64NVME")]
+    pub checksum_crc64nvme: Option<String>,
+    #[serde(rename = "ChecksumSHA1")]
+    pub checksum_sha1: Option<String>,
+    #[serde(rename = "ChecksumSHA256")]
+    pub checksum_sha256: Option<String>,
+    /// `COMPOSITE` or `FULL_OBJECT`.
+    pub checksum_type: Option<String>,
+}
+
+/// The parts of a multipart object, returned by `get_object_attributes`.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectParts {
+    /// The total number of parts.
+    #[serde(default)]
+    pub parts_count: usize,
+    #[serde(default)]
+    pub part_number_marker: usize,
+    #[serde(default)]
+    pub next_part_number_marker: usize,
+    #[serde(default)]
+    pub max_parts: usize,
+    #[serde(default)]
+    pub is_truncated: bool,
+    /// The parts, only returned if the object was uploaded with a checksum.
+    #[serde(default, rename = "Part")]
+    pub parts: Vec<ObjectPart>,
+}
+
+/// A part of [ObjectParts].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectPart {
+    pub part_number: usize,
+    pub size: usize,
+    #[serde(rename = "ChecksumCRC32")]
+    pub checksum_crc32: Option<String>,
+    #[serde(rename = "ChecksumCRC32C")]
+    pub checksum_crc32c: Option<String>,
+    #[serde(rename = "ChecksumCRC64NVME")]
+    pub checksum_crc64nvme: Option<String>,
+    #[serde(rename = "ChecksumSHA1")]
+    pub checksum_sha1: Option<String>,
+    #[serde(rename = "ChecksumSHA256")]
+    pub checksum_sha256: Option<String>,
+}
+
 /// The container element for an Object Lock rule.
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -421,11 +729,27 @@ pub struct ObjectVersion {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Owner {
+    #[serde(default)]
     pub display_name: String,
     #[serde(rename = "ID")]
     pub id: String,
 }
 
+/// The container element for a bucket's ownership controls.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OwnershipControls {
+    #[serde(rename = "Rule", default)]
+    pub rules: Vec<OwnershipControlsRule>,
+}
+
+/// The container element for an ownership control rule.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OwnershipControlsRule {
+    pub object_ownership: ObjectOwnership,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Part {
@@ -452,6 +776,27 @@ pub struct PublicAccessBlockConfiguration {
     pub restrict_public_buckets: bool,
 }
 
+/// Specifies how requests are redirected.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct Redirect {
+    pub host_name: Option<String>,
+    pub http_redirect_code: Option<String>,
+    pub protocol: Option<String>,
+    pub replace_key_prefix_with: Option<String>,
+    pub replace_key_with: Option<String>,
+}
+
+/// Specifies the redirect behavior of all requests to a website endpoint of a bucket.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RedirectAllRequestsTo {
+    /// Name of the host where requests are redirected.
+    pub host_name: String,
+    /// Protocol to use when redirecting requests. The default is the protocol that is used in the original request.
+    pub protocol: Option<String>,
+}
+
 /// A container for replication rules. You can add up to 1,000 rules. The maximum size of a replication configuration is 2 MB.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
@@ -470,6 +815,14 @@ pub struct ReplicationRule {
     pub priority: Option<i64>,
 }
 
+/// Container for Payer.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RequestPaymentConfiguration {
+    /// Specifies who pays for the download and request fees.
+    pub payer: Payer,
+}
+
 /// Object representation of request XML of `put_object_retention` API
 /// and response XML of `get_object_retention` API.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -482,6 +835,30 @@ pub struct Retention {
     pub retain_until_date: UtcTime,
 }
 
+/// A container for describing a condition that must be met for the specified redirect to apply.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct RoutingCondition {
+    pub http_error_code_returned_equals: Option<String>,
+    pub key_prefix_equals: Option<String>,
+}
+
+/// Specifies the redirect behavior and when a redirect is applied.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RoutingRule {
+    pub condition: Option<RoutingCondition>,
+    pub redirect: Redirect,
+}
+
+/// Container for the routing rules of the website configuration.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct RoutingRules {
+    #[serde(rename = "RoutingRule", default)]
+    pub rules: Vec<RoutingRule>,
+}
+
 /// Describes the default server-side encryption to apply to new objects in the bucket.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
@@ -527,7 +904,7 @@ pub struct Tag {
 }
 
 /// A collection for a set of tags
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct TagSet {
     #[serde(rename = "Tag", default)]
@@ -538,9 +915,18 @@ pub struct TagSet {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Tagging {
+    #[serde(default)]
     pub tag_set: TagSet,
 }
 
+/// Container for granting information of the logging target.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct TargetGrants {
+    #[serde(default)]
+    pub grant: Vec<Grant>,
+}
+
 /// Describes the versioning state of an Amazon S3 bucket.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
@@ -558,16 +944,66 @@ pub struct VersioningConfiguration {
     pub status: Option<VersioningStatus>,
 }
 
+/// Specifies website configuration parameters for an Amazon S3 bucket.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct WebsiteConfiguration {
+    /// The name of the error document for the website.
+    pub error_document: Option<ErrorDocument>,
+    /// The name of the index document for the website.
+    pub index_document: Option<IndexDocument>,
+    /// The redirect behavior for every request to this bucket's website endpoint.
+    ///
+    /// **Note**: If you specify this property, you can't specify any other property.
+    pub redirect_all_requests_to: Option<RedirectAllRequestsTo>,
+    /// Rules that define when a redirect is applied and the redirect behavior.
+    pub routing_rules: Option<RoutingRules>,
+}
+
 //////////////////  Enum Type
 
+/// The transfer acceleration state of the bucket.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub enum BucketAccelerateStatus {
+    Enabled,
+    Suspended,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub enum ChecksumAlgorithm {
     CRC32,
     CRC32C,
+    CRC64NVME,
     SHA1,
     SHA256,
 }
 
+impl ChecksumAlgorithm {
+    /// Returns the header of the checksum value, like `x-amz-checksum-crc32`.
+    pub fn header_name(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::CRC32 => "x-amz-checksum-crc32",
+            ChecksumAlgorithm::CRC32C => "x-amz-checksum-crc32c",
+            ChecksumAlgorithm::CRC64NVME => "x-amz-checksum-crc64nvme",
+            ChecksumAlgorithm::SHA1 => "x-amz-checksum-sha1",
+            ChecksumAlgorithm::SHA256 => "x-amz-checksum-sha256",
+        }
+    }
+
+    /// Returns the algorithm of the checksum header, see [header_name](Self::header_name).
+    pub fn from_header_name(name: &str) -> Option<Self> {
+        [
+            ChecksumAlgorithm::CRC32,
+            ChecksumAlgorithm::CRC32C,
+            ChecksumAlgorithm::CRC64NVME,
+            ChecksumAlgorithm::SHA1,
+            ChecksumAlgorithm::SHA256,
+        ]
+        .into_iter()
+        .find(|algorithm| algorithm.header_name().eq_ignore_ascii_case(name))
+    }
+}
+
 /// Type of grantee
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub enum GranteeType {
@@ -576,6 +1012,28 @@ pub enum GranteeType {
     Group,
 }
 
+/// Specifies the output format of the inventory results. Valid Values: `CSV | ORC | Parquet`
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub enum InventoryFormat {
+    CSV,
+    ORC,
+    Parquet,
+}
+
+/// Specifies how frequently inventory results are produced. Valid Values: `Daily | Weekly`
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub enum InventoryFrequency {
+    Daily,
+    Weekly,
+}
+
+/// Object versions to include in the inventory list. Valid Values: `All | Current`
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub enum InventoryIncludedObjectVersions {
+    All,
+    Current,
+}
+
 /// Specifies whether MFA delete is enabled in the bucket versioning configuration.
 /// This element is only returned if the bucket has been configured with MFA delete.
 /// If the bucket has never been so configured, this element is not returned.
@@ -591,6 +1049,52 @@ pub enum LegalHoldStatus {
     OFF,
 }
 
+/// The container element for object ownership for a bucket's ownership controls.
+/// Valid Values: `BucketOwnerPreferred | ObjectWriter | BucketOwnerEnforced`
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub enum ObjectOwnership {
+    /// Objects uploaded to the bucket change ownership to the bucket owner
+    /// if the objects are uploaded with the `bucket-owner-full-control` canned ACL.
+    BucketOwnerPreferred,
+    /// The uploading account will own the object
+    /// if the object is uploaded with the `bucket-owner-full-control` canned ACL.
+    ObjectWriter,
+    /// Access control lists (ACLs) are disabled and no longer affect permissions.
+    /// The bucket owner automatically owns and has full control over every object in the bucket.
+    BucketOwnerEnforced,
+}
+
+/// The attribute returned by `get_object_attributes`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub enum ObjectAttribute {
+    ETag,
+    Checksum,
+    ObjectParts,
+    StorageClass,
+    ObjectSize,
+}
+
+impl ObjectAttribute {
+    /// Returns the value in the `x-amz-object-attributes` header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ObjectAttribute::ETag => "ETag",
+            ObjectAttribute::Checksum => "Checksum",
+            ObjectAttribute::ObjectParts => "ObjectParts",
+            ObjectAttribute::StorageClass => "StorageClass",
+            ObjectAttribute::ObjectSize => "ObjectSize",
+        }
+    }
+}
+
+/// Who pays for the download and request fees, Valid Values: `Requester | BucketOwner`
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+pub enum Payer {
+    Requester,
+    #[default]
+    BucketOwner,
+}
+
 /// Retention mode, Valid Values: `GOVERNANCE | COMPLIANCE`
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
 pub enum RetentionMode {
@@ -609,6 +1113,42 @@ pub enum Permission {
     READ_ACP,
 }
 
+/// The class of storage used to store the object.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StorageClass {
+    Standard,
+    ReducedRedundancy,
+    StandardIa,
+    OnezoneIa,
+    IntelligentTiering,
+    Glacier,
+    DeepArchive,
+    GlacierIr,
+    Outposts,
+    Snow,
+    ExpressOnezone,
+}
+
+impl StorageClass {
+    /// Returns the value of the `x-amz-storage-class` header, like `STANDARD_IA`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageClass::Standard => "STANDARD",
+            StorageClass::ReducedRedundancy => "REDUCED_REDUNDANCY",
+            StorageClass::StandardIa => "STANDARD_IA",
+            StorageClass::OnezoneIa => "ONEZONE_IA",
+            StorageClass::IntelligentTiering => "INTELLIGENT_TIERING",
+            StorageClass::Glacier => "GLACIER",
+            StorageClass::DeepArchive => "DEEP_ARCHIVE",
+            StorageClass::GlacierIr => "GLACIER_IR",
+            StorageClass::Outposts => "OUTPOSTS",
+            StorageClass::Snow => "SNOW",
+            StorageClass::ExpressOnezone => "EXPRESS_ONEZONE",
+        }
+    }
+}
+
 /// Valid Values: `Enabled | Disabled`
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub enum Status {
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// An event record of the bucket notification, in the JSON format of S3 event messages.
+///
+/// Returned by `listen_bucket_notification` of MinIO.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationRecord {
+    #[serde(default)]
+    pub event_version: String,
+    #[serde(default)]
+    pub event_source: String,
+    #[serde(default)]
+    pub aws_region: String,
+    #[serde(default)]
+    pub event_time: String,
+    /// The event type, like `s3:ObjectCreated:Put`.
+    pub event_name: String,
+    #[serde(default)]
+    pub user_identity: NotificationIdentity,
+    #[serde(default)]
+    pub request_parameters: HashMap<String, String>,
+    #[serde(default)]
+    pub response_elements: HashMap<String, String>,
+    pub s3: NotificationS3,
+}
+
+/// The identity of the user who caused the event.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationIdentity {
+    #[serde(default)]
+    pub principal_id: String,
+}
+
+/// The bucket and object of a [NotificationRecord].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationS3 {
+    #[serde(default)]
+    pub s3_schema_version: String,
+    #[serde(default)]
+    pub configuration_id: String,
+    pub bucket: NotificationBucket,
+    pub object: NotificationObject,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationBucket {
+    pub name: String,
+    #[serde(default)]
+    pub owner_identity: NotificationIdentity,
+    #[serde(default)]
+    pub arn: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationObject {
+    /// The URL-encoded key of the object.
+    pub key: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default, rename = "eTag")]
+    pub etag: String,
+    #[serde(default)]
+    pub content_type: String,
+    #[serde(default)]
+    pub user_metadata: HashMap<String, String>,
+    #[serde(default)]
+    pub version_id: String,
+    #[serde(default)]
+    pub sequencer: String,
+}
+
+/// A line of the response of `listen_bucket_notification`.
+#[cfg(feature = "ext")]
+#[derive(Debug, Deserialize)]
+pub(crate) struct NotificationInfo {
+    #[serde(rename = "Records", default)]
+    pub records: Option<Vec<NotificationRecord>>,
+}
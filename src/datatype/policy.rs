@@ -0,0 +1,341 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// The access policy of a bucket, serialized to the JSON S3 expects.
+///
+/// Use [PolicyBuilder] to build the common policies.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BucketPolicy {
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(deserialize_with = "one_or_many")]
+    pub statement: Vec<PolicyStatement>,
+}
+
+impl BucketPolicy {
+    /// try get json string
+    pub fn to_json(&self) -> crate::error::Result<String> {
+        serde_json::to_string(self).map_err(|e| crate::error::Error::ValueError(e.to_string()))
+    }
+
+    /// try from json string
+    pub fn from_json(v: &str) -> crate::error::Result<Self> {
+        serde_json::from_str(v).map_err(|e| crate::error::Error::ValueError(e.to_string()))
+    }
+}
+
+/// A statement of a [BucketPolicy].
+///
+/// The `Not*` elements match everything except the listed values,
+/// a statement uses either the element or its `Not*` counterpart.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PolicyStatement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    pub effect: PolicyEffect,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_principal: Option<Principal>,
+    #[serde(
+        default,
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub action: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub not_action: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub resource: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub not_resource: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<serde_json::Value>,
+}
+
+impl PolicyStatement {
+    fn new(effect: PolicyEffect) -> Self {
+        Self {
+            sid: None,
+            effect,
+            principal: Some(Principal::all()),
+            not_principal: None,
+            action: vec![],
+            not_action: vec![],
+            resource: vec![],
+            not_resource: vec![],
+            condition: None,
+        }
+    }
+
+    /// Create a statement which allows the actions, the principal is everyone by default.
+    pub fn allow() -> Self {
+        Self::new(PolicyEffect::Allow)
+    }
+
+    /// Create a statement which denies the actions, the principal is everyone by default.
+    pub fn deny() -> Self {
+        Self::new(PolicyEffect::Deny)
+    }
+
+    /// Set the statement id.
+    pub fn sid<S: Into<String>>(mut self, sid: S) -> Self {
+        self.sid = Some(sid.into());
+        self
+    }
+
+    /// Set the principal.
+    pub fn principal(mut self, principal: Principal) -> Self {
+        self.principal = Some(principal);
+        self.not_principal = None;
+        self
+    }
+
+    /// Set the principal which the statement does not apply to, it replaces the principal.
+    pub fn not_principal(mut self, principal: Principal) -> Self {
+        self.principal = None;
+        self.not_principal = Some(principal);
+        self
+    }
+
+    /// Add an action, like `s3:GetObject`.
+    pub fn action<S: Into<String>>(mut self, action: S) -> Self {
+        self.action.push(action.into());
+        self
+    }
+
+    /// Add an action which the statement does not apply to.
+    pub fn not_action<S: Into<String>>(mut self, action: S) -> Self {
+        self.not_action.push(action.into());
+        self
+    }
+
+    /// Add a resource ARN.
+    pub fn resource<S: Into<String>>(mut self, resource: S) -> Self {
+        self.resource.push(resource.into());
+        self
+    }
+
+    /// Add a resource ARN which the statement does not apply to.
+    pub fn not_resource<S: Into<String>>(mut self, resource: S) -> Self {
+        self.not_resource.push(resource.into());
+        self
+    }
+
+    /// Add the ARN of a bucket, `arn:aws:s3:::{bucket}`.
+    pub fn bucket_resource(self, bucket: &str) -> Self {
+        self.resource(format!("arn:aws:s3:::{bucket}"))
+    }
+
+    /// Add the ARN of objects in a bucket with the prefix, `arn:aws:s3:::{bucket}/{prefix}*`.
+    pub fn object_resource(self, bucket: &str, prefix: &str) -> Self {
+        self.resource(format!("arn:aws:s3:::{bucket}/{prefix}*"))
+    }
+
+    /// Set the condition, like `{"StringEquals": {"s3:prefix": ["photos/"]}}`.
+    pub fn condition(mut self, condition: serde_json::Value) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+}
+
+/// Whether the statement allows or denies access.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum PolicyEffect {
+    Allow,
+    Deny,
+}
+
+/// The principal of a [PolicyStatement].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Principal {
+    /// `"*"`, everyone.
+    Wildcard(String),
+    Aws {
+        #[serde(rename = "AWS", deserialize_with = "one_or_many")]
+        aws: Vec<String>,
+    },
+}
+
+impl Principal {
+    /// All users, `{"AWS": ["*"]}`.
+    pub fn all() -> Self {
+        Self::Aws {
+            aws: vec!["*".to_string()],
+        }
+    }
+
+    /// The AWS accounts or users, like `arn:aws:iam::123456789012:root`.
+    pub fn aws<S: Into<String>>(arns: impl IntoIterator<Item = S>) -> Self {
+        Self::Aws {
+            aws: arns.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Build a [BucketPolicy].
+/// ## Example
+/// ```rust
+/// use sc_minio::datatype::{PolicyBuilder, PolicyStatement};
+///
+/// let policy = PolicyBuilder::public_read("bucket").build();
+/// let policy = PolicyBuilder::new()
+///     .statement(
+///         PolicyStatement::allow()
+///             .action("s3:PutObject")
+///             .object_resource("bucket", "uploads/"),
+///     )
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PolicyBuilder {
+    statements: Vec<PolicyStatement>,
+}
+
+impl PolicyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a statement.
+    pub fn statement(mut self, statement: PolicyStatement) -> Self {
+        self.statements.push(statement);
+        self
+    }
+
+    /// Everyone can list the bucket and download all objects.
+    pub fn public_read(bucket: &str) -> Self {
+        Self::new()
+            .statement(
+                PolicyStatement::allow()
+                    .action("s3:GetBucketLocation")
+                    .action("s3:ListBucket")
+                    .bucket_resource(bucket),
+            )
+            .statement(
+                PolicyStatement::allow()
+                    .action("s3:GetObject")
+                    .object_resource(bucket, ""),
+            )
+    }
+
+    /// Everyone can download objects with the prefix, but cannot list them.
+    pub fn download_only(bucket: &str, prefix: &str) -> Self {
+        Self::new()
+            .statement(
+                PolicyStatement::allow()
+                    .action("s3:GetBucketLocation")
+                    .bucket_resource(bucket),
+            )
+            .statement(
+                PolicyStatement::allow()
+                    .action("s3:GetObject")
+                    .object_resource(bucket, prefix),
+            )
+    }
+
+    pub fn build(self) -> BucketPolicy {
+        BucketPolicy {
+            version: "2012-10-17".to_string(),
+            id: None,
+            statement: self.statements,
+        }
+    }
+}
+
+/// deserialize a single value or an array of values to [Vec].
+fn one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        One(T),
+        Many(Vec<T>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(v) => vec![v],
+        OneOrMany::Many(v) => v,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BucketPolicy, PolicyBuilder, Principal};
+
+    #[test]
+    fn test_public_read() {
+        let policy = PolicyBuilder::public_read("bucket").build();
+        assert_eq!(
+            policy.to_json().unwrap(),
+            r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Principal":{"AWS":["*"]},"Action":["s3:GetBucketLocation","s3:ListBucket"],"Resource":["arn:aws:s3:::bucket"]},{"Effect":"Allow","Principal":{"AWS":["*"]},"Action":["s3:GetObject"],"Resource":["arn:aws:s3:::bucket/*"]}]}"#
+        );
+        let policy2 = BucketPolicy::from_json(&policy.to_json().unwrap()).unwrap();
+        assert_eq!(policy, policy2);
+    }
+
+    #[test]
+    fn test_download_only() {
+        let policy = PolicyBuilder::download_only("bucket", "public/").build();
+        assert_eq!(policy.statement.len(), 2);
+        assert_eq!(
+            policy.statement[1].resource,
+            vec!["arn:aws:s3:::bucket/public/*"]
+        );
+    }
+
+    #[test]
+    fn test_from_json() {
+        let txt = r#"{
+            "Version": "2012-10-17",
+            "Statement": {
+                "Sid": "AddPerm",
+                "Effect": "Deny",
+                "Principal": "*",
+                "Action": "s3:DeleteObject",
+                "Resource": "arn:aws:s3:::bucket/*",
+                "Condition": {"StringEquals": {"s3:prefix": ["photos/"]}}
+            }
+        }"#;
+        let policy = BucketPolicy::from_json(txt).unwrap();
+        let statement = &policy.statement[0];
+        assert_eq!(statement.sid.as_deref(), Some("AddPerm"));
+        assert_eq!(
+            statement.principal,
+            Some(Principal::Wildcard("*".to_string()))
+        );
+        assert_eq!(statement.action, vec!["s3:DeleteObject"]);
+        assert!(statement.condition.is_some());
+    }
+
+    #[test]
+    fn test_not_elements() {
+        let txt = r#"{"Version":"2012-10-17","Statement":[{"Effect":"Deny","NotPrincipal":{"AWS":["arn:aws:iam::123456789012:root"]},"NotAction":["s3:GetObject"],"NotResource":["arn:aws:s3:::bucket/public/*"]}]}"#;
+        let policy = BucketPolicy::from_json(txt).unwrap();
+        let statement = &policy.statement[0];
+        assert_eq!(statement.principal, None);
+        assert!(statement.action.is_empty());
+        assert!(statement.resource.is_empty());
+        assert_eq!(statement.not_action, vec!["s3:GetObject"]);
+        assert_eq!(statement.not_resource, vec!["arn:aws:s3:::bucket/public/*"]);
+        assert_eq!(policy.to_json().unwrap(), txt);
+    }
+}
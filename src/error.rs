@@ -84,6 +84,10 @@ pub struct S3Error {
     pub host_id: Option<String>,
     pub bucket_name: Option<String>,
     pub object_name: Option<String>,
+    /// The region of the bucket, returned with a redirect error.
+    pub region: Option<String>,
+    /// The time of the server, returned with a `RequestTimeTooSkewed` error.
+    pub server_time: Option<String>,
 }
 
 impl std::fmt::Display for S3Error {
@@ -141,13 +145,134 @@ pub enum Error {
 
     /// indicate I/O error, had on S3 operation.
     IoError(std::io::Error),
+
+    /// indicate the JSON content of an object is invalid.
+    Deserialize(serde_json::Error),
+
+    /// indicate the content of an object is not a valid text in its charset.
+    Encoding(String),
+
+    /// indicate the object already exists when uploading with `If-None-Match: *`.
+    AlreadyExists(S3Error),
+
+    /// indicate the downloaded content does not match the checksum of the object.
+    ChecksumMismatch(String),
+}
+
+/// The category of an [Error], returned by [Error::kind].
+///
+/// | Kind | Variants of [Error] |
+/// |------|---------------------|
+/// | [ErrorKind::Value] | [ValueError](Error::ValueError) |
+/// | [ErrorKind::S3] | [S3Error](Error::S3Error), [AlreadyExists](Error::AlreadyExists) |
+/// | [ErrorKind::Xml] | [XmlError](Error::XmlError) |
+/// | [ErrorKind::Io] | [IoError](Error::IoError) |
+/// | [ErrorKind::Transport] | [RequestError](Error::RequestError), [HttpError](Error::HttpError) not timed out, [UnknownResponse](Error::UnknownResponse) |
+/// | [ErrorKind::Timeout] | [HttpError](Error::HttpError) timed out |
+/// | [ErrorKind::Content] | [Deserialize](Error::Deserialize), [Encoding](Error::Encoding), [MessageDecodeError](Error::MessageDecodeError), [SelectObejectError](Error::SelectObejectError) |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// an illegal argument was used, the request was not sent.
+    Value,
+    /// S3 service returned an error response.
+    S3,
+    /// the XML response could not be parsed.
+    Xml,
+    /// I/O error of local files or streams.
+    Io,
+    /// connecting to the S3 service failed, or the response was unexpected.
+    Transport,
+    /// the request timed out.
+    Timeout,
+    /// the content of an object could not be decoded.
+    Content,
+}
+
+/// Error codes of S3 for missing buckets, objects, versions and uploads.
+const NOT_FOUND_CODES: [&str; 5] = [
+    "NoSuchBucket",
+    "NoSuchKey",
+    "NoSuchVersion",
+    "NoSuchUpload",
+    "NotFound",
+];
+
+/// Error codes of S3 for transient failures.
+const RETRYABLE_CODES: [&str; 6] = [
+    "InternalError",
+    "ServiceUnavailable",
+    "SlowDown",
+    "RequestTimeout",
+    "RequestTimeTooSkewed",
+    "OperationAborted",
+];
+
+impl Error {
+    /// Return the category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::ValueError(_) => ErrorKind::Value,
+            Error::S3Error(_) | Error::AlreadyExists(_) => ErrorKind::S3,
+            Error::XmlError(_) => ErrorKind::Xml,
+            Error::IoError(_) => ErrorKind::Io,
+            Error::HttpError(e) if e.is_timeout() => ErrorKind::Timeout,
+            Error::RequestError(_) | Error::HttpError(_) | Error::UnknownResponse(_) => {
+                ErrorKind::Transport
+            }
+            Error::Deserialize(_)
+            | Error::Encoding(_)
+            | Error::ChecksumMismatch(_)
+            | Error::MessageDecodeError(_)
+            | Error::SelectObejectError(_) => ErrorKind::Content,
+        }
+    }
+
+    /// Return the [S3Error] if S3 service returned an error response.
+    pub fn as_s3(&self) -> Option<&S3Error> {
+        match self {
+            Error::S3Error(e) | Error::AlreadyExists(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Whether the bucket, object, version or multipart upload does not exist.
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Error::UnknownResponse(res) => res.status() == reqwest::StatusCode::NOT_FOUND,
+            _ => self
+                .as_s3()
+                .is_some_and(|e| NOT_FOUND_CODES.contains(&e.code.as_str())),
+        }
+    }
+
+    /// Whether the error is transient, and the request may succeed if sent again,
+    /// such as timeouts, connection errors, `5xx` responses and `SlowDown`.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::HttpError(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+            Error::RequestError(_) => true,
+            Error::UnknownResponse(res) => {
+                res.status().is_server_error()
+                    || res.status() == reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            Error::S3Error(e) => RETRYABLE_CODES.contains(&e.code.as_str()),
+            Error::IoError(e) => matches!(
+                e.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::Interrupted
+            ),
+            _ => false,
+        }
+    }
 }
 
 impl StdError for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Error::RequestError(e) => e.source(),
-            Error::S3Error(e) => e.source(),
+            Error::S3Error(e) | Error::AlreadyExists(e) => e.source(),
             _ => None,
         }
     }
@@ -166,6 +291,10 @@ impl fmt::Display for Error {
             Error::MessageDecodeError(e)=> write!(f, "{}", e),
             Error::SelectObejectError(e)=> write!(f, "{}", e),
             Error::IoError(e) => write!(f, "{}", e),
+            Error::Deserialize(e) => write!(f, "{}", e),
+            Error::Encoding(e) => write!(f, "{}", e),
+            Error::AlreadyExists(e) => write!(f, "{}", e),
+            Error::ChecksumMismatch(e) => write!(f, "{}", e),
         }
     }
 }
@@ -230,9 +359,42 @@ impl From<crate::xml::error::Error> for Error {
 
 #[cfg(test)]
 mod tests {
-    use super::S3Error;
+    use super::{Error, ErrorKind, S3Error};
     use crate::error::XmlError;
 
+    #[test]
+    fn test_error_kind() {
+        let s3_error = |code: &str| {
+            let res = format!(
+                "<Error><Code>{code}</Code><Message></Message><RequestId>4442587FB7D0A2F9</RequestId></Error>"
+            );
+            Error::S3Error(res.as_str().try_into().unwrap())
+        };
+        let err = s3_error("NoSuchKey");
+        assert_eq!(err.kind(), ErrorKind::S3);
+        assert_eq!(err.as_s3().unwrap().code, "NoSuchKey");
+        assert!(err.is_not_found());
+        assert!(!err.is_retryable());
+
+        let err = s3_error("SlowDown");
+        assert!(!err.is_not_found());
+        assert!(err.is_retryable());
+
+        let err = Error::from("invalid bucket name");
+        assert_eq!(err.kind(), ErrorKind::Value);
+        assert!(err.as_s3().is_none());
+        assert!(!err.is_retryable());
+
+        let err = Error::from(std::io::Error::from(std::io::ErrorKind::ConnectionReset));
+        assert_eq!(err.kind(), ErrorKind::Io);
+        assert!(err.is_retryable());
+
+        let res = hyper::Response::builder().status(503).body("").unwrap();
+        let err = Error::from(reqwest::Response::from(res));
+        assert_eq!(err.kind(), ErrorKind::Transport);
+        assert!(err.is_retryable());
+    }
+
     #[test]
     fn test_s3_error() {
         let res = r#"<?xml version="1.0" encoding="UTF-8"?>
@@ -2,12 +2,21 @@
 
 pub mod client;
 mod credentials;
+pub mod crypto;
 mod data;
 pub mod error;
+#[cfg(feature = "metrics")]
+mod metrics;
 pub mod provider;
 mod signer;
 pub mod sse;
+#[cfg(feature = "ext")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ext")))]
+pub mod store;
 pub mod time;
+#[cfg(feature = "mock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+pub mod transport;
 pub mod datatype;
 mod utils;
 pub mod xml;
@@ -15,4 +24,10 @@ pub mod xml;
 pub use crate::client::Minio;
 pub use crate::credentials::Credentials;
 pub use crate::data::Data;
-pub use crate::signer::{presign_v4, sign_request_v4, sign_v4_authorization};
+pub use crate::signer::{
+    presign_v4, sign_request_v2, sign_request_v4, sign_v2_authorization, sign_v4_authorization,
+    SignatureVersion,
+};
+pub use crate::utils::compute_multipart_etag;
+#[cfg(feature = "fs-tokio")]
+pub use crate::utils::compute_multipart_etag_file;
@@ -0,0 +1,198 @@
+//! Metrics emitted by the `metrics` feature, recorded by the [metrics] crate.
+//!
+//! - `sc_minio_requests_total`: counter of requests, labeled by `operation` and `status`.
+//! - `sc_minio_request_duration_seconds`: histogram of request latency, labeled by `operation`.
+//! - `sc_minio_bytes_uploaded_total`: counter of request body bytes.
+//! - `sc_minio_bytes_downloaded_total`: counter of response body bytes, if the `Content-Length` is known.
+//! - `sc_minio_errors_total`: counter of S3 error responses, labeled by `code`.
+use std::time::Instant;
+
+use hyper::{HeaderMap, Method};
+use reqwest::Response;
+
+use crate::error::Result;
+
+/// The sub-resources which name an operation, like `tagging` of `GetObjectTagging`.
+const SUB_RESOURCES: [&str; 24] = [
+    "accelerate",
+    "acl",
+    "analytics",
+    "attributes",
+    "cors",
+    "encryption",
+    "intelligent-tiering",
+    "inventory",
+    "legal-hold",
+    "lifecycle",
+    "location",
+    "logging",
+    "metrics",
+    "notification",
+    "ownershipControls",
+    "policy",
+    "policyStatus",
+    "publicAccessBlock",
+    "replication",
+    "requestPayment",
+    "retention",
+    "tagging",
+    "versioning",
+    "website",
+];
+
+/// Name the S3 operation of a request, like `GetObject` or `PutBucketTagging`,
+/// from its method, whether it targets a bucket or an object, and its sub-resource.
+pub(crate) fn operation_name(
+    method: &Method,
+    bucket: bool,
+    object: bool,
+    headers: Option<&HeaderMap>,
+    query: &str,
+) -> String {
+    let keys: Vec<&str> = query
+        .split('&')
+        .map(|kv| kv.split_once('=').map_or(kv, |(k, _)| k))
+        .collect();
+    let has = |key: &str| keys.contains(&key);
+    let copy = headers.is_some_and(|h| h.contains_key("x-amz-copy-source"));
+    let verb = match method.as_str() {
+        "GET" => "Get",
+        "PUT" => "Put",
+        "POST" => "Post",
+        "DELETE" => "Delete",
+        "HEAD" => "Head",
+        other => other,
+    };
+    let target = if object { "Object" } else { "Bucket" };
+    let name = match (method.as_str(), bucket, object) {
+        ("GET", false, _) => "ListBuckets",
+        ("POST", true, false) if has("delete") => "DeleteObjects",
+        ("GET", true, false) if has("uploads") => "ListMultipartUploads",
+        ("GET", true, false) if has("versions") => "ListObjectVersions",
+        ("GET", true, false) if has("events") => "ListenBucketNotification",
+        (_, true, false) if has("object-lock") => return format!("{verb}ObjectLockConfiguration"),
+        ("POST", true, true) if has("uploads") => "CreateMultipartUpload",
+        ("PUT", true, true) if has("uploadId") && copy => "UploadPartCopy",
+        ("PUT", true, true) if has("uploadId") => "UploadPart",
+        ("POST", true, true) if has("uploadId") => "CompleteMultipartUpload",
+        ("DELETE", true, true) if has("uploadId") => "AbortMultipartUpload",
+        ("GET", true, true) if has("uploadId") => "ListParts",
+        ("POST", true, true) if has("select") => "SelectObjectContent",
+        ("POST", true, true) if has("restore") => "RestoreObject",
+        _ => {
+            if let Some(sub) = keys.iter().find(|k| SUB_RESOURCES.contains(k)) {
+                let sub: String = sub
+                    .split('-')
+                    .map(|w| w[..1].to_uppercase() + &w[1..])
+                    .collect();
+                return format!("{verb}{target}{sub}");
+            }
+            match (method.as_str(), object) {
+                ("GET", false) if has("list-type") => "ListObjectsV2",
+                ("GET", false) => "ListObjects",
+                ("PUT", false) => "CreateBucket",
+                ("PUT", true) if copy => "CopyObject",
+                _ => return format!("{verb}{target}"),
+            }
+        }
+    };
+    name.to_string()
+}
+
+/// Measure a request from being sent until its response is received.
+pub(crate) struct RequestMetrics {
+    operation: String,
+    start: Instant,
+}
+
+impl RequestMetrics {
+    pub(crate) fn start(operation: String) -> Self {
+        Self {
+            operation,
+            start: Instant::now(),
+        }
+    }
+
+    pub(crate) fn finish(self, res: &Result<Response>) {
+        let operation = self.operation;
+        metrics::histogram!("sc_minio_request_duration_seconds", "operation" => operation.clone())
+            .record(self.start.elapsed().as_secs_f64());
+        let status = match res {
+            Ok(res) => {
+                if let Some(len) = res.content_length() {
+                    metrics::counter!("sc_minio_bytes_downloaded_total").increment(len);
+                }
+                res.status().as_u16().to_string()
+            }
+            Err(_) => "error".to_string(),
+        };
+        metrics::counter!("sc_minio_requests_total", "operation" => operation, "status" => status)
+            .increment(1);
+    }
+}
+
+/// Count the bytes of a request body.
+pub(crate) fn record_upload(len: usize) {
+    metrics::counter!("sc_minio_bytes_uploaded_total").increment(len as u64);
+}
+
+/// Count an S3 error response.
+pub(crate) fn record_s3_error(code: &str) {
+    metrics::counter!("sc_minio_errors_total", "code" => code.to_string()).increment(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::{HeaderMap, Method};
+
+    use super::operation_name;
+
+    #[test]
+    fn test_operation_name() {
+        let name = |method: Method, bucket, object, query| {
+            operation_name(&method, bucket, object, None, query)
+        };
+        assert_eq!(name(Method::GET, false, false, ""), "ListBuckets");
+        assert_eq!(name(Method::HEAD, true, false, ""), "HeadBucket");
+        assert_eq!(name(Method::PUT, true, false, ""), "CreateBucket");
+        assert_eq!(
+            name(Method::GET, true, false, "list-type=2&prefix=a"),
+            "ListObjectsV2"
+        );
+        assert_eq!(
+            name(Method::GET, true, false, "location"),
+            "GetBucketLocation"
+        );
+        assert_eq!(
+            name(Method::DELETE, true, false, "tagging="),
+            "DeleteBucketTagging"
+        );
+        assert_eq!(
+            name(Method::PUT, true, false, "object-lock"),
+            "PutObjectLockConfiguration"
+        );
+        assert_eq!(name(Method::POST, true, false, "delete"), "DeleteObjects");
+        assert_eq!(name(Method::GET, true, true, "versionId=1"), "GetObject");
+        assert_eq!(
+            name(Method::GET, true, true, "legal-hold&versionId=1"),
+            "GetObjectLegalHold"
+        );
+        assert_eq!(
+            name(Method::POST, true, true, "uploads"),
+            "CreateMultipartUpload"
+        );
+        assert_eq!(
+            name(Method::PUT, true, true, "partNumber=1&uploadId=a"),
+            "UploadPart"
+        );
+        assert_eq!(
+            name(Method::POST, true, true, "select&select-type=2"),
+            "SelectObjectContent"
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-amz-copy-source", "/bucket/a".parse().unwrap());
+        let copy = operation_name(&Method::PUT, true, true, Some(&headers), "");
+        assert_eq!(copy, "CopyObject");
+    }
+}
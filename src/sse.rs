@@ -37,7 +37,8 @@ impl SseCustomerKey {
             ));
         }
         let b64_key = base64_encode(key);
-        let md5_key = md5sum_hash(key.as_bytes());
+        let md5_key = md5sum_hash(key.as_bytes())
+            .ok_or_else(|| ValueError::from("SSE-C keys need MD5, which is not available"))?;
         let mut headers = HeaderMap::new();
         headers.insert(
             "X-Amz-Server-Side-Encryption-Customer-Algorithm",
@@ -0,0 +1,127 @@
+//! An abstraction over the core object operations, to write code generic over the storage
+//! and substitute an in-memory fake of [ObjectStore] in tests.
+//!
+//! ## Example
+//! ```rust
+//! use std::collections::BTreeMap;
+//! use std::sync::Mutex;
+//!
+//! use bytes::Bytes;
+//! use futures::StreamExt;
+//! use sc_minio::client::ObjectStat;
+//! use sc_minio::datatype::Object;
+//! use sc_minio::error::{Result, ValueError};
+//! use sc_minio::store::{ObjectStore, ObjectStream, StoreFuture};
+//!
+//! #[derive(Default)]
+//! struct MemoryStore {
+//!     objects: Mutex<BTreeMap<(String, String), Bytes>>,
+//! }
+//!
+//! impl ObjectStore for MemoryStore {
+//!     fn get_object<'a>(&'a self, bucket: &'a str, key: &'a str) -> StoreFuture<'a, Bytes> {
+//!         let data = self.objects.lock().unwrap().get(&(bucket.into(), key.into())).cloned();
+//!         Box::pin(async move { data.ok_or_else(|| ValueError::from("no such key").into()) })
+//!     }
+//!
+//!     fn put_object<'a>(&'a self, bucket: &'a str, key: &'a str, data: Bytes) -> StoreFuture<'a, ()> {
+//!         self.objects.lock().unwrap().insert((bucket.into(), key.into()), data);
+//!         Box::pin(async { Ok(()) })
+//!     }
+//!
+//!     fn remove_object<'a>(&'a self, bucket: &'a str, key: &'a str) -> StoreFuture<'a, ()> {
+//!         self.objects.lock().unwrap().remove(&(bucket.into(), key.into()));
+//!         Box::pin(async { Ok(()) })
+//!     }
+//!
+//!     fn stat_object<'a>(&'a self, bucket: &'a str, key: &'a str) -> StoreFuture<'a, Option<ObjectStat>> {
+//!         let objects = self.objects.lock().unwrap();
+//!         let stat = objects
+//!             .get(&(bucket.into(), key.into()))
+//!             .map(|data| ObjectStat::new(bucket, key, "", data.len()));
+//!         Box::pin(async { Ok(stat) })
+//!     }
+//!
+//!     fn list_objects_stream<'a>(&'a self, bucket: &'a str, prefix: &'a str) -> ObjectStream<'a> {
+//!         // build the [Object] of the keys here.
+//!         Box::pin(futures::stream::empty::<Result<Object>>())
+//!     }
+//! }
+//!
+//! async fn archive<S: ObjectStore>(store: &S, key: &str) -> Result<()> {
+//!     let data = store.get_object("bucket", key).await?;
+//!     store.put_object("archive", key, data).await?;
+//!     store.remove_object("bucket", key).await
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<()> {
+//! let store = MemoryStore::default();
+//! store.put_object("bucket", "file.txt", Bytes::from_static(b"hello")).await?;
+//! archive(&store, "file.txt").await?;
+//! assert!(store.stat_object("bucket", "file.txt").await?.is_none());
+//! assert_eq!(store.stat_object("archive", "file.txt").await?.unwrap().size(), 5);
+//! # Ok(())
+//! # }
+//! ```
+use std::pin::Pin;
+
+use bytes::Bytes;
+use futures::{Future, Stream};
+
+use crate::client::ObjectStat;
+use crate::datatype::Object;
+use crate::error::Result;
+use crate::Minio;
+
+pub type StoreFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+pub type ObjectStream<'a> = Pin<Box<dyn Stream<Item = Result<Object>> + Send + 'a>>;
+
+/// define the core operations on objects, implemented by [Minio].
+pub trait ObjectStore: Send + Sync {
+    /// Return the data of an object.
+    fn get_object<'a>(&'a self, bucket: &'a str, key: &'a str) -> StoreFuture<'a, Bytes>;
+
+    /// Upload the data to an object.
+    fn put_object<'a>(&'a self, bucket: &'a str, key: &'a str, data: Bytes) -> StoreFuture<'a, ()>;
+
+    /// Remove an object.
+    fn remove_object<'a>(&'a self, bucket: &'a str, key: &'a str) -> StoreFuture<'a, ()>;
+
+    /// Return the [ObjectStat] of an object, [None] if the object does not exist.
+    fn stat_object<'a>(
+        &'a self,
+        bucket: &'a str,
+        key: &'a str,
+    ) -> StoreFuture<'a, Option<ObjectStat>>;
+
+    /// Return a stream of all objects starting with the prefix of the bucket.
+    fn list_objects_stream<'a>(&'a self, bucket: &'a str, prefix: &'a str) -> ObjectStream<'a>;
+}
+
+impl ObjectStore for Minio {
+    fn get_object<'a>(&'a self, bucket: &'a str, key: &'a str) -> StoreFuture<'a, Bytes> {
+        Box::pin(async move { Ok(Minio::get_object(self, bucket, key).await?.bytes().await?) })
+    }
+
+    fn put_object<'a>(&'a self, bucket: &'a str, key: &'a str, data: Bytes) -> StoreFuture<'a, ()> {
+        Box::pin(Minio::put_object(self, bucket, key, data))
+    }
+
+    fn remove_object<'a>(&'a self, bucket: &'a str, key: &'a str) -> StoreFuture<'a, ()> {
+        Box::pin(async move { Minio::remove_object(self, bucket, key).await.map(|_| ()) })
+    }
+
+    fn stat_object<'a>(
+        &'a self,
+        bucket: &'a str,
+        key: &'a str,
+    ) -> StoreFuture<'a, Option<ObjectStat>> {
+        Box::pin(Minio::stat_object(self, bucket, key))
+    }
+
+    fn list_objects_stream<'a>(&'a self, bucket: &'a str, prefix: &'a str) -> ObjectStream<'a> {
+        Minio::list_objects_stream(self, bucket.into(), prefix)
+    }
+}
@@ -18,6 +18,12 @@ impl UtcTime {
         Self::new(Utc::now())
     }
 
+    /// Returns the wrapped [DateTime]
+    #[inline]
+    pub fn datetime(&self) -> DateTime<Utc> {
+        self.0
+    }
+
     #[inline]
     pub(crate) fn before(&self, timestamp: i64) -> bool {
         timestamp < self.0.timestamp()
@@ -44,6 +50,14 @@ impl UtcTime {
     pub fn aws_format_date(&self) -> String {
         self.0.format("%Y%m%d").to_string()
     }
+
+    /// format date to RFC 1123, like`Sun, 10 Sep 2023 08:26:43 GMT`
+    ///
+    /// Used in S3 signatures version '2'.
+    #[inline]
+    pub fn http_format_time(&self) -> String {
+        self.0.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+    }
 }
 
 impl From<DateTime<Utc>> for UtcTime {
@@ -52,6 +66,12 @@ impl From<DateTime<Utc>> for UtcTime {
     }
 }
 
+impl From<UtcTime> for DateTime<Utc> {
+    fn from(time: UtcTime) -> Self {
+        time.0
+    }
+}
+
 impl Default for UtcTime {
     /// default: current utc time.
     fn default() -> Self {
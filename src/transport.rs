@@ -0,0 +1,107 @@
+//! Pluggable HTTP transport, used to unit-test code without a running server.
+//!
+//! ## Example
+//! ```rust
+//! use sc_minio::{provider::StaticProvider, Minio};
+//! use sc_minio::transport::MockTransport;
+//!
+//! # async fn example() -> sc_minio::error::Result<()> {
+//! let transport = MockTransport::new(|_req| {
+//!     hyper::Response::builder()
+//!         .status(200)
+//!         .body("hello minio".into())
+//!         .unwrap()
+//! });
+//! let minio = Minio::builder()
+//!     .endpoint("localhost:9000")
+//!     .provider(StaticProvider::new("access", "secret", None))
+//!     .transport(transport.clone())
+//!     .build()
+//!     .unwrap();
+//! let text = minio.get_object("bucket", "file.txt").await?.text().await?;
+//! assert_eq!(text, "hello minio");
+//! assert_eq!(transport.requests()[0].url.path(), "/bucket/file.txt");
+//! # Ok(())
+//! # }
+//! ```
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use futures::Future;
+use hyper::{HeaderMap, Method};
+use reqwest::{Body, Request, Response, Url};
+
+use crate::error::Result;
+
+pub type TransportFuture = Pin<Box<dyn Future<Output = Result<Response>> + Send>>;
+
+/// define the sender of signed HTTP requests.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: Request) -> TransportFuture;
+}
+
+/// A request captured by [MockTransport].
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    /// Body of the request, [None] if the body is a stream.
+    pub body: Option<Bytes>,
+}
+
+type MockHandler = dyn Fn(&MockRequest) -> hyper::Response<Body> + Send + Sync;
+
+/// An in-memory [Transport] which answers every request with a handler
+/// and records the requests it received.
+///
+/// Cloned [MockTransport] share the recorded requests.
+#[derive(Clone)]
+pub struct MockTransport {
+    handler: Arc<MockHandler>,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockTransport {
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(&MockRequest) -> hyper::Response<Bytes> + Send + Sync + 'static,
+    {
+        Self::streaming(move |req| handler(req).map(Body::from))
+    }
+
+    /// Create a [MockTransport] whose handler answers with a [Body],
+    /// e.g. a stream created by [Body::wrap_stream] to simulate errors in the middle of a response.
+    pub fn streaming<F>(handler: F) -> Self
+    where
+        F: Fn(&MockRequest) -> hyper::Response<Body> + Send + Sync + 'static,
+    {
+        Self {
+            handler: Arc::new(handler),
+            requests: Default::default(),
+        }
+    }
+
+    /// Get the requests received so far.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: Request) -> TransportFuture {
+        let request = MockRequest {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(|b| b.as_bytes())
+                .map(Bytes::copy_from_slice),
+        };
+        let response = (self.handler)(&request);
+        self.requests.lock().unwrap().push(request);
+        Box::pin(async move { Ok(Response::from(response)) })
+    }
+}
@@ -1,8 +1,12 @@
 #![allow(unused)]
 use base64::Engine;
+use bytes::Bytes;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use sha2::Digest;
 
+use crate::crypto::HashContext;
+use crate::datatype::ChecksumAlgorithm;
 use crate::error::ValueError;
 
 pub static EMPTY_CONTENT_SHA256: &str =
@@ -14,7 +18,7 @@ static _VALID_NAME: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^[a-z0-9][a-z0-9.-]{1,61}[a-z0-9]$").unwrap());
 
 pub static _VALID_ENDPOINT: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^[A-Za-z0-9_\-.]+(:\d+)?$").unwrap());
+    Lazy::new(|| Regex::new(r"^([A-Za-z0-9_\-.]+|\[[0-9A-Fa-f:.]+\])(:\d+)?$").unwrap());
 
 static _IS_URLENCODE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^([0-9a-zA-Z-.~_]|(%[0-9A-F]{2}))*$").unwrap());
@@ -57,6 +61,24 @@ pub fn check_bucket_name(name: &str) -> Result<bool, ValueError> {
     return Ok(true);
 }
 
+/// Check whether object name is valid
+pub fn check_object_name(name: &str) -> Result<bool, ValueError> {
+    if name.is_empty() {
+        Err(ValueError::from("Object name cannot be empty."))?;
+    }
+    if name.len() > 1024 {
+        Err(ValueError::from(
+            "Object name must be at most 1024 bytes long in UTF-8 encoding.",
+        ))?;
+    }
+    if name.chars().any(|c| c.is_ascii_control()) {
+        Err(ValueError::from(
+            "Object name cannot contain ASCII control characters.",
+        ))?;
+    }
+    Ok(true)
+}
+
 /// Encode arbitrary octets as base64 using the provided [base64::engine::general_purpose::STANDARD].
 /// Returns a `String`.
 #[inline]
@@ -65,8 +87,166 @@ pub fn base64_encode<T: AsRef<[u8]>>(input: T) -> String {
 }
 
 /// Compute MD5 of data and return hash as Base64 encoded value.
-pub fn md5sum_hash(data: &[u8]) -> String {
-    base64_encode(md5::compute(data).0)
+///
+/// Return [None] if MD5 is not available in the [CryptoProvider](crate::crypto::CryptoProvider).
+pub fn md5sum_hash(data: &[u8]) -> Option<String> {
+    crate::crypto::provider().md5(data).map(base64_encode)
+}
+
+/// Compute SHA-256 of data and return hash as Base64 encoded value.
+pub fn sha256sum_hash(data: &[u8]) -> String {
+    base64_encode(crate::crypto::provider().sha256(data))
+}
+
+fn md5_digest(data: &[u8]) -> Result<[u8; 16], ValueError> {
+    crate::crypto::provider()
+        .md5(data)
+        .ok_or_else(|| ValueError::from("MD5 is not available"))
+}
+
+fn md5_context() -> Result<Box<dyn HashContext>, ValueError> {
+    crate::crypto::provider()
+        .md5_context()
+        .ok_or_else(|| ValueError::from("MD5 is not available"))
+}
+
+/// Return the ETag of a multipart object from the MD5 of its parts.
+fn multipart_etag(digests: &[[u8; 16]]) -> Result<String, ValueError> {
+    let digest = md5_digest(&digests.concat())?;
+    Ok(format!("{}-{}", hex::encode(digest), digests.len()))
+}
+
+/// Compute the ETag of an object uploaded through multipart upload with the parts,
+/// `hex(md5(md5(part_1) + ... + md5(part_n)))-n`, without quotes.
+///
+/// Return a [ValueError] if MD5 is not available.
+///
+/// **Note**: The ETag of objects encrypted with SSE-C or SSE-KMS is not computed from MD5.
+/// ## Example
+/// ```rust
+/// use sc_minio::compute_multipart_etag;
+/// # use sc_minio::error::ValueError;
+///
+/// # fn example() -> Result<(), ValueError> {
+/// let etag = compute_multipart_etag(&["hello ".into(), "minio".into()])?;
+/// assert!(etag.ends_with("-2"));
+/// # Ok(())
+/// # }
+/// ```
+pub fn compute_multipart_etag(parts: &[Bytes]) -> Result<String, ValueError> {
+    let digests = parts
+        .iter()
+        .map(|part| md5_digest(part))
+        .collect::<Result<Vec<_>, _>>()?;
+    multipart_etag(&digests)
+}
+
+/// Compute the ETag of a file uploaded through multipart upload, split into parts of `part_size` bytes.
+///
+/// See [compute_multipart_etag].
+#[cfg(feature = "fs-tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fs-tokio")))]
+pub async fn compute_multipart_etag_file<P: AsRef<std::path::Path>>(
+    path: P,
+    part_size: usize,
+) -> crate::error::Result<String> {
+    let digests = file_part_digests(path.as_ref(), part_size).await?;
+    Ok(multipart_etag(&digests)?)
+}
+
+/// Compute the ETag of a file uploaded in a single request, the MD5 of the file in hex.
+#[cfg(feature = "fs-tokio")]
+pub(crate) async fn compute_etag_file(
+    path: &std::path::Path,
+    len: usize,
+) -> crate::error::Result<String> {
+    let digests = file_part_digests(path, len.max(1)).await?;
+    let digest = match digests.first() {
+        Some(digest) => *digest,
+        None => md5_digest(&[])?,
+    };
+    Ok(hex::encode(digest))
+}
+
+/// Return the MD5 of the parts of the file, every part is `part_size` bytes except the last one.
+#[cfg(feature = "fs-tokio")]
+async fn file_part_digests(
+    path: &std::path::Path,
+    part_size: usize,
+) -> crate::error::Result<Vec<[u8; 16]>> {
+    use tokio::io::AsyncReadExt;
+
+    if part_size == 0 {
+        return Err(ValueError::from("part size must be greater than 0").into());
+    }
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut digests = vec![];
+    let mut buf = vec![0; part_size.min(1024 * 1024)];
+    loop {
+        let mut context = md5_context()?;
+        let mut remain = part_size;
+        while remain > 0 {
+            let len = remain.min(buf.len());
+            let n = file.read(&mut buf[..len]).await?;
+            if n == 0 {
+                break;
+            }
+            context.update(&buf[..n]);
+            remain -= n;
+        }
+        if remain == part_size {
+            break;
+        }
+        let digest = context
+            .finalize()
+            .try_into()
+            .map_err(|_| ValueError::from("invalid MD5 digest"))?;
+        digests.push(digest);
+    }
+    Ok(digests)
+}
+
+/// Incremental hasher of the `x-amz-checksum-*` values, which are Base64 encoded.
+///
+/// SHA-1 and SHA-256 are computed by the installed [CryptoProvider](crate::crypto::CryptoProvider).
+pub(crate) enum ChecksumHasher {
+    Crc32(crc32fast::Hasher),
+    Crc32c(u32),
+    Crc64Nvme(crc64fast_nvme::Digest),
+    Sha(Box<dyn HashContext>),
+}
+
+impl ChecksumHasher {
+    /// Return [None] if the algorithm is not available from the crypto provider.
+    pub fn new(algorithm: &ChecksumAlgorithm) -> Option<Self> {
+        match algorithm {
+            ChecksumAlgorithm::CRC32 => Some(Self::Crc32(crc32fast::Hasher::new())),
+            ChecksumAlgorithm::CRC32C => Some(Self::Crc32c(0)),
+            ChecksumAlgorithm::CRC64NVME => Some(Self::Crc64Nvme(crc64fast_nvme::Digest::new())),
+            ChecksumAlgorithm::SHA1 => crate::crypto::provider().sha1_context().map(Self::Sha),
+            ChecksumAlgorithm::SHA256 => {
+                Some(Self::Sha(crate::crypto::provider().sha256_context()))
+            }
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Crc32(h) => h.update(data),
+            Self::Crc32c(crc) => *crc = crc32c::crc32c_append(*crc, data),
+            Self::Crc64Nvme(h) => h.write(data),
+            Self::Sha(h) => h.update(data),
+        }
+    }
+
+    pub fn finalize(self) -> String {
+        match self {
+            Self::Crc32(h) => base64_encode(h.finalize().to_be_bytes()),
+            Self::Crc32c(crc) => base64_encode(crc.to_be_bytes()),
+            Self::Crc64Nvme(h) => base64_encode(h.sum64().to_be_bytes()),
+            Self::Sha(h) => base64_encode(h.finalize()),
+        }
+    }
 }
 
 /// uri encode every byte except the unreserved characters: 'A'-'Z', 'a'-'z', '0'-'9', '-', '.', '_', and '~'.
@@ -84,6 +264,31 @@ pub fn urlencode_binary(data: &[u8], safe_slash: bool) -> String {
     }
 }
 
+/// escape the special characters of XML text: `&`, `<`, `>`, `"` and `'`.
+pub fn xml_escape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// wrap the ETag in double quotes for conditional headers, unless already quoted or `*`.
+pub fn quote_etag(etag: String) -> String {
+    if etag.starts_with('"') || etag == "*" {
+        etag
+    } else {
+        format!("\"{etag}\"")
+    }
+}
+
 /// check text is uuid foramt
 pub fn is_uuid(text: &str) -> bool {
     text.len() == 36 && _VALIE_UUID.is_match(text)
@@ -95,7 +300,7 @@ pub fn is_urlencoded(text: &str) -> bool {
 }
 
 pub fn trim_bytes(b: &[u8]) -> &[u8] {
-    let mut start = 0;
+    let mut start = b.len();
     let mut end = b.len();
     for i in 0..b.len() {
         if !b[i].is_ascii_whitespace() {
@@ -118,9 +323,10 @@ pub fn trim_bytes(b: &[u8]) -> &[u8] {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::{is_urlencoded, trim_bytes};
+    use crate::utils::{compute_multipart_etag, is_urlencoded, trim_bytes, xml_escape};
 
-    use super::check_bucket_name;
+    use super::{check_bucket_name, check_object_name, ChecksumHasher};
+    use crate::datatype::ChecksumAlgorithm;
     #[test]
     fn test_check_bucket_name() {
         assert!(check_bucket_name("test").is_ok());
@@ -136,8 +342,84 @@ mod tests {
         assert!(!is_urlencoded("uri%2Gencode"));
     }
 
+    #[test]
+    fn test_check_object_name() {
+        assert!(check_object_name("test/test.txt").is_ok());
+        assert!(check_object_name("файл (1).txt").is_ok());
+        assert!(check_object_name(&"a".repeat(1024)).is_ok());
+        assert!(check_object_name(&"a".repeat(1025)).is_err());
+        assert!(check_object_name(&"я".repeat(513)).is_err());
+        assert!(check_object_name("").is_err());
+        assert!(check_object_name("test\ntest").is_err());
+    }
+
     #[test]
     fn test_trim_bytes() {
         assert_eq!(trim_bytes(" hello \n".as_bytes()), "hello".as_bytes());
+        assert_eq!(trim_bytes(" \n".as_bytes()), "".as_bytes());
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(xml_escape("a&b<c>\"d'"), "a&amp;b&lt;c&gt;&quot;d&apos;");
+        assert_eq!(xml_escape("minio"), "minio");
+    }
+
+    #[test]
+    #[cfg(not(feature = "fips"))]
+    fn test_compute_multipart_etag() {
+        assert_eq!(
+            compute_multipart_etag(&["hello ".into(), "minio".into()]).unwrap(),
+            "516c28bba7d5fca6df500e1828265cac-2"
+        );
+        assert_eq!(
+            compute_multipart_etag(&["hello minio".into()]).unwrap(),
+            "a108be67015ea1c8f51f762b1c4b0724-1"
+        );
+    }
+    #[tokio::main]
+    #[test]
+    #[cfg(all(feature = "fs-tokio", not(feature = "fips")))]
+    async fn test_compute_etag_file() {
+        use bytes::Bytes;
+
+        use super::{compute_etag_file, compute_multipart_etag_file};
+
+        // parts larger than the read buffer.
+        let data: Vec<u8> = (0..5 * 1024 * 1024 / 2).map(|i| i as u8).collect();
+        let path = std::env::temp_dir().join("sc-minio-test-compute-etag-file");
+        std::fs::write(&path, &data).unwrap();
+        let part_size = 2 * 1024 * 1024;
+        let parts: Vec<Bytes> = data.chunks(part_size).map(Bytes::copy_from_slice).collect();
+        assert_eq!(
+            compute_multipart_etag_file(&path, part_size).await.unwrap(),
+            compute_multipart_etag(&parts).unwrap()
+        );
+        assert_eq!(
+            compute_etag_file(&path, data.len()).await.unwrap(),
+            hex::encode(md5::compute(&data).0)
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_checksum_hasher() {
+        let checksum = |algorithm| {
+            let mut hasher = ChecksumHasher::new(&algorithm).unwrap();
+            hasher.update(b"1234");
+            hasher.update(b"56789");
+            hasher.finalize()
+        };
+        assert_eq!(checksum(ChecksumAlgorithm::CRC32), "y/Q5Jg==");
+        assert_eq!(checksum(ChecksumAlgorithm::CRC32C), "4waSgw==");
+        assert_eq!(checksum(ChecksumAlgorithm::CRC64NVME), "rosUhgp5mIg=");
+        assert_eq!(
+            checksum(ChecksumAlgorithm::SHA1),
+            "98O8HYCOBHMq32eZZczDTKeuNEE="
+        );
+        assert_eq!(
+            checksum(ChecksumAlgorithm::SHA256),
+            "FeKw08M4keuw8e9gnsQZQgwg4yDOlMZfvIwzEkSOsiU="
+        );
     }
 }
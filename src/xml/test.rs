@@ -4,10 +4,14 @@ mod test {
     use serde::Deserialize;
 
     use crate::datatype::{
-        AccessControlPolicy, CompleteMultipartUploadResult, CopyPartResult,
-        InitiateMultipartUploadResult, LegalHold, ListAllMyBucketsResult, ListBucketResult,
-        ListMultipartUploadsResult, ListPartsResult, ListVersionsResult, ObjectLockConfiguration,
-        Retention, Tagging, VersioningConfiguration,
+        AccelerateConfiguration, AccessControlPolicy, BucketAccelerateStatus, BucketLoggingStatus,
+        CompleteMultipartUploadResult, CopyPartResult, InitiateMultipartUploadResult,
+        InventoryConfiguration, InventoryFormat, InventoryFrequency,
+        InventoryIncludedObjectVersions, LegalHold, ListAllMyBucketsResult, ListBucketResult,
+        ListInventoryConfigurationsResult, ListMultipartUploadsResult, ListPartsResult,
+        ListVersionsResult, ObjectAttributes, ObjectLockConfiguration, ObjectOwnership,
+        OwnershipControls, Payer, RequestPaymentConfiguration, Retention, Tagging,
+        VersioningConfiguration, WebsiteConfiguration,
     };
 
     macro_rules! test_datatypes {
@@ -127,6 +131,39 @@ mod test {
         </CopyPartResult>"#
     );
 
+    test_datatypes!(
+        ObjectAttributes,
+        test_object_attributes,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+        <GetObjectAttributesResponse>
+            <ETag>string</ETag>
+            <Checksum>
+                <ChecksumCRC32C>string</ChecksumCRC32C>
+                <ChecksumType>COMPOSITE</ChecksumType>
+            </Checksum>
+            <ObjectParts>
+                <IsTruncated>false</IsTruncated>
+                <MaxParts>1000</MaxParts>
+                <NextPartNumberMarker>2</NextPartNumberMarker>
+                <PartNumberMarker>0</PartNumberMarker>
+                <Part>
+                    <ChecksumCRC32C>string</ChecksumCRC32C>
+                    <PartNumber>1</PartNumber>
+                    <Size>5242880</Size>
+                </Part>
+                <Part>
+                    <ChecksumCRC32C>string</ChecksumCRC32C>
+                    <PartNumber>2</PartNumber>
+                    <Size>1024</Size>
+                </Part>
+                <PartsCount>2</PartsCount>
+            </ObjectParts>
+            <StorageClass>STANDARD</StorageClass>
+            <ObjectSize>5243904</ObjectSize>
+        </GetObjectAttributesResponse>
+        "#
+    );
+
     test_datatypes!(
         ObjectLockConfiguration,
         test_object_lock_configure,
@@ -284,11 +321,12 @@ mod test {
         <ListAllMyBucketsResult>
             <Buckets>
                 <Bucket>
-                    <CreationDate>timestamp</CreationDate>
+                    <CreationDate>2023-09-10T08:16:28.230Z</CreationDate>
                     <Name>string</Name>
                 </Bucket>
                 <Bucket>
-                    <CreationDate>timestamp2</CreationDate>
+                    <BucketRegion>eu-west-1</BucketRegion>
+                    <CreationDate>2023-09-11T08:16:28.000Z</CreationDate>
                     <Name>string2</Name>
                 </Bucket>
             </Buckets>
@@ -384,6 +422,294 @@ mod test {
         "#
     );
 
+    #[test]
+    fn test_ownership_controls() {
+        let txt = r#"<OwnershipControls xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Rule>
+                <ObjectOwnership>BucketOwnerEnforced</ObjectOwnership>
+            </Rule>
+        </OwnershipControls>"#;
+        let res = crate::xml::de::from_str::<OwnershipControls>(txt).unwrap();
+        assert_eq!(
+            res.rules[0].object_ownership,
+            ObjectOwnership::BucketOwnerEnforced
+        );
+        assert_eq!(
+            crate::xml::ser::to_string(&res).unwrap(),
+            "<OwnershipControls><Rule><ObjectOwnership>BucketOwnerEnforced</ObjectOwnership></Rule></OwnershipControls>"
+        );
+    }
+
+    #[test]
+    fn test_accelerate_configuration() {
+        let txt = r#"<AccelerateConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Status>Suspended</Status>
+        </AccelerateConfiguration>"#;
+        let res = crate::xml::de::from_str::<AccelerateConfiguration>(txt).unwrap();
+        assert_eq!(res.status, Some(BucketAccelerateStatus::Suspended));
+        let txt = r#"<AccelerateConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/"/>"#;
+        let res = crate::xml::de::from_str::<AccelerateConfiguration>(txt).unwrap();
+        assert_eq!(res.status, None);
+        let conf = AccelerateConfiguration {
+            status: Some(BucketAccelerateStatus::Enabled),
+        };
+        assert_eq!(
+            crate::xml::ser::to_string(&conf).unwrap(),
+            "<AccelerateConfiguration><Status>Enabled</Status></AccelerateConfiguration>"
+        );
+    }
+
+    #[test]
+    fn test_request_payment_configuration() {
+        let txt = r#"<RequestPaymentConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Payer>Requester</Payer>
+        </RequestPaymentConfiguration>"#;
+        let res = crate::xml::de::from_str::<RequestPaymentConfiguration>(txt).unwrap();
+        assert_eq!(res.payer, Payer::Requester);
+        assert_eq!(
+            crate::xml::ser::to_string(&res).unwrap(),
+            "<RequestPaymentConfiguration><Payer>Requester</Payer></RequestPaymentConfiguration>"
+        );
+    }
+
+    #[test]
+    fn test_bucket_logging_status() {
+        let txt = r#"<BucketLoggingStatus xmlns="http://doc.s3.amazonaws.com/2006-03-01">
+            <LoggingEnabled>
+                <TargetBucket>mybucketlogs</TargetBucket>
+                <TargetPrefix>mybucket-access_log-/</TargetPrefix>
+                <TargetGrants>
+                    <Grant>
+                        <Grantee xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="AmazonCustomerByEmail">
+                            <EmailAddress>user@company.com</EmailAddress>
+                        </Grantee>
+                        <Permission>READ</Permission>
+                    </Grant>
+                </TargetGrants>
+            </LoggingEnabled>
+        </BucketLoggingStatus>"#;
+        let res = crate::xml::de::from_str::<BucketLoggingStatus>(txt).unwrap();
+        let enabled = res.logging_enabled.as_ref().unwrap();
+        assert_eq!(enabled.target_bucket, "mybucketlogs");
+        assert_eq!(enabled.target_prefix, "mybucket-access_log-/");
+        assert_eq!(enabled.target_grants.as_ref().unwrap().grant.len(), 1);
+        let xml = crate::xml::ser::to_string(&res).unwrap();
+        let res = crate::xml::de::from_str::<BucketLoggingStatus>(&xml).unwrap();
+        assert_eq!(
+            res.logging_enabled.unwrap().target_grants.unwrap().grant[0]
+                .grantee
+                .as_ref()
+                .unwrap()
+                .email_address
+                .as_deref(),
+            Some("user@company.com")
+        );
+
+        let txt = r#"<BucketLoggingStatus xmlns="http://doc.s3.amazonaws.com/2006-03-01" />"#;
+        let res = crate::xml::de::from_str::<BucketLoggingStatus>(txt).unwrap();
+        assert!(res.logging_enabled.is_none());
+        assert_eq!(
+            crate::xml::ser::to_string(&BucketLoggingStatus::default()).unwrap(),
+            "<BucketLoggingStatus></BucketLoggingStatus>"
+        );
+    }
+
+    #[test]
+    fn test_website_configuration() {
+        let txt = r#"<WebsiteConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <IndexDocument>
+                <Suffix>index.html</Suffix>
+            </IndexDocument>
+            <ErrorDocument>
+                <Key>Error.html</Key>
+            </ErrorDocument>
+            <RoutingRules>
+                <RoutingRule>
+                    <Condition>
+                        <KeyPrefixEquals>docs/</KeyPrefixEquals>
+                    </Condition>
+                    <Redirect>
+                        <ReplaceKeyPrefixWith>documents/</ReplaceKeyPrefixWith>
+                    </Redirect>
+                </RoutingRule>
+            </RoutingRules>
+        </WebsiteConfiguration>"#;
+        let res = crate::xml::de::from_str::<WebsiteConfiguration>(txt).unwrap();
+        assert_eq!(res.index_document.as_ref().unwrap().suffix, "index.html");
+        assert_eq!(res.error_document.as_ref().unwrap().key, "Error.html");
+        let rules = &res.routing_rules.as_ref().unwrap().rules;
+        assert_eq!(rules.len(), 1);
+        assert_eq!(
+            rules[0].redirect.replace_key_prefix_with.as_deref(),
+            Some("documents/")
+        );
+        assert_eq!(
+            crate::xml::ser::to_string(&res).unwrap(),
+            "<WebsiteConfiguration><ErrorDocument><Key>Error.html</Key></ErrorDocument><IndexDocument><Suffix>index.html</Suffix></IndexDocument><RoutingRules><RoutingRule><Condition><KeyPrefixEquals>docs/</KeyPrefixEquals></Condition><Redirect><ReplaceKeyPrefixWith>documents/</ReplaceKeyPrefixWith></Redirect></RoutingRule></RoutingRules></WebsiteConfiguration>"
+        );
+
+        let txt = r#"<WebsiteConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <RedirectAllRequestsTo>
+                <HostName>example.com</HostName>
+                <Protocol>https</Protocol>
+            </RedirectAllRequestsTo>
+        </WebsiteConfiguration>"#;
+        let res = crate::xml::de::from_str::<WebsiteConfiguration>(txt).unwrap();
+        let redirect = res.redirect_all_requests_to.unwrap();
+        assert_eq!(redirect.host_name, "example.com");
+        assert_eq!(redirect.protocol.as_deref(), Some("https"));
+    }
+
+    #[test]
+    fn test_inventory_configuration() {
+        let txt = r#"<InventoryConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Destination>
+                <S3BucketDestination>
+                    <AccountId>123456789012</AccountId>
+                    <Bucket>arn:aws:s3:::reports</Bucket>
+                    <Format>CSV</Format>
+                    <Prefix>inventory</Prefix>
+                </S3BucketDestination>
+            </Destination>
+            <IsEnabled>true</IsEnabled>
+            <Filter>
+                <Prefix>logs/</Prefix>
+            </Filter>
+            <Id>report1</Id>
+            <IncludedObjectVersions>All</IncludedObjectVersions>
+            <OptionalFields>
+                <Field>Size</Field>
+                <Field>ETag</Field>
+            </OptionalFields>
+            <Schedule>
+                <Frequency>Daily</Frequency>
+            </Schedule>
+        </InventoryConfiguration>"#;
+        let res = crate::xml::de::from_str::<InventoryConfiguration>(txt).unwrap();
+        let dest = &res.destination.s3_bucket_destination;
+        assert_eq!(dest.account_id.as_deref(), Some("123456789012"));
+        assert_eq!(dest.bucket, "arn:aws:s3:::reports");
+        assert_eq!(dest.format, InventoryFormat::CSV);
+        assert_eq!(res.filter.as_ref().unwrap().prefix, "logs/");
+        assert_eq!(res.id, "report1");
+        assert_eq!(
+            res.included_object_versions,
+            InventoryIncludedObjectVersions::All
+        );
+        assert!(res.is_enabled);
+        assert_eq!(
+            res.optional_fields.as_ref().unwrap().fields,
+            ["Size", "ETag"]
+        );
+        assert_eq!(res.schedule.frequency, InventoryFrequency::Daily);
+        assert_eq!(
+            crate::xml::ser::to_string(&res).unwrap(),
+            "<InventoryConfiguration><Destination><S3BucketDestination><AccountId>123456789012</AccountId><Bucket>arn:aws:s3:::reports</Bucket><Format>CSV</Format><Prefix>inventory</Prefix></S3BucketDestination></Destination><Filter><Prefix>logs/</Prefix></Filter><Id>report1</Id><IncludedObjectVersions>All</IncludedObjectVersions><IsEnabled>true</IsEnabled><OptionalFields><Field>Size</Field><Field>ETag</Field></OptionalFields><Schedule><Frequency>Daily</Frequency></Schedule></InventoryConfiguration>"
+        );
+
+        let txt = r#"<ListInventoryConfigurationsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <InventoryConfiguration>
+                <Id>report1</Id>
+                <IsEnabled>false</IsEnabled>
+                <Destination><S3BucketDestination><Bucket>arn:aws:s3:::reports</Bucket><Format>Parquet</Format></S3BucketDestination></Destination>
+                <Schedule><Frequency>Weekly</Frequency></Schedule>
+                <IncludedObjectVersions>Current</IncludedObjectVersions>
+            </InventoryConfiguration>
+            <IsTruncated>true</IsTruncated>
+            <NextContinuationToken>token-2</NextContinuationToken>
+        </ListInventoryConfigurationsResult>"#;
+        let res = crate::xml::de::from_str::<ListInventoryConfigurationsResult>(txt).unwrap();
+        assert_eq!(res.inventory_configurations.len(), 1);
+        let config = &res.inventory_configurations[0];
+        assert!(!config.is_enabled);
+        assert!(config.filter.is_none());
+        assert!(config.optional_fields.is_none());
+        assert_eq!(
+            config.destination.s3_bucket_destination.format,
+            InventoryFormat::Parquet
+        );
+        assert!(res.is_truncated);
+        assert_eq!(res.next_continuation_token.as_deref(), Some("token-2"));
+    }
+
+    #[test]
+    fn test_retention_date() {
+        use chrono::{TimeZone, Utc};
+
+        let txt = r#"<Retention><Mode>COMPLIANCE</Mode><RetainUntilDate>2023-09-10T08:16:28.230Z</RetainUntilDate></Retention>"#;
+        let res = crate::xml::de::from_str::<Retention>(txt).unwrap();
+        assert_eq!(res.mode, crate::datatype::RetentionMode::COMPLIANCE);
+        let date = Utc.with_ymd_and_hms(2023, 9, 10, 8, 16, 28).unwrap()
+            + chrono::Duration::milliseconds(230);
+        assert_eq!(res.retain_until_date.datetime(), date);
+    }
+
+    #[test]
+    fn test_bucket_creation_date() {
+        use chrono::{TimeZone, Utc};
+
+        let txt = r#"<ListAllMyBucketsResult><Buckets><Bucket><BucketRegion>eu-west-1</BucketRegion><CreationDate>2023-09-10T08:16:28.000Z</CreationDate><Name>bucket</Name></Bucket></Buckets><Owner><DisplayName>minio</DisplayName><ID>02d6176db174dc93cb1b899f7c6078f08654445fe8cf1b6ce98d8855f66bdbf4</ID></Owner></ListAllMyBucketsResult>"#;
+        let res = crate::xml::de::from_str::<ListAllMyBucketsResult>(txt).unwrap();
+        let bucket = &res.buckets.bucket[0];
+        assert_eq!(bucket.name, "bucket");
+        assert_eq!(bucket.bucket_region.as_deref(), Some("eu-west-1"));
+        let date = Utc.with_ymd_and_hms(2023, 9, 10, 8, 16, 28).unwrap();
+        assert_eq!(bucket.creation_date.datetime(), date);
+    }
+
+    #[test]
+    fn test_list_bucket_objects() {
+        let txt = r#"<ListBucketResult><Name>bucket</Name><Prefix></Prefix><KeyCount>2</KeyCount><MaxKeys>1000</MaxKeys><Delimiter></Delimiter><IsTruncated>false</IsTruncated>
+            <Contents><Key>archive.tar</Key><LastModified>2023-09-10T08:16:28.230Z</LastModified><ETag>"5eb63bbbe01eeed093cb22bb8f5acdc3"</ETag><Size>11</Size><Owner><ID>02d6176db174dc93cb1b899f7c6078f08654445fe8cf1b6ce98d8855f66bdbf4</ID><DisplayName>minio</DisplayName></Owner><StorageClass>GLACIER</StorageClass></Contents>
+            <Contents><Key>file.txt</Key><LastModified>2023-09-11T08:16:28.000Z</LastModified><ETag>"d41d8cd98f00b204e9800998ecf8427e"</ETag><Size>0</Size></Contents>
+            </ListBucketResult>"#;
+        let res = crate::xml::de::from_str::<ListBucketResult>(txt).unwrap();
+        let obj = &res.contents[0];
+        assert_eq!(obj.key, "archive.tar");
+        assert_eq!(obj.last_modified.aws_format_time(), "20230910T081628Z");
+        assert_eq!(obj.etag(), "5eb63bbbe01eeed093cb22bb8f5acdc3");
+        assert_eq!(obj.size, 11);
+        assert_eq!(obj.storage_class, "GLACIER");
+        let owner = obj.owner.as_ref().unwrap();
+        assert_eq!(
+            owner.id,
+            "02d6176db174dc93cb1b899f7c6078f08654445fe8cf1b6ce98d8855f66bdbf4"
+        );
+        assert_eq!(owner.display_name, "minio");
+        let obj = &res.contents[1];
+        assert_eq!(obj.storage_class, "");
+        assert!(obj.owner.is_none());
+        assert!(obj.last_modified > res.contents[0].last_modified);
+    }
+
+    #[test]
+    fn test_list_bucket_common_prefixes() {
+        // keys: a/1, a/2, b/1, c, listed with the delimiter `/`
+        let txt = r#"<ListBucketResult><Name>bucket</Name><Prefix></Prefix><KeyCount>3</KeyCount><MaxKeys>1000</MaxKeys><Delimiter>/</Delimiter><IsTruncated>false</IsTruncated>
+            <Contents><Key>c</Key><LastModified>2023-09-10T08:16:28.230Z</LastModified><ETag>"5eb63bbbe01eeed093cb22bb8f5acdc3"</ETag><Size>11</Size><StorageClass>STANDARD</StorageClass></Contents>
+            <CommonPrefixes><Prefix>a/</Prefix></CommonPrefixes><CommonPrefixes><Prefix>b/</Prefix></CommonPrefixes>
+            </ListBucketResult>"#;
+        let res = crate::xml::de::from_str::<ListBucketResult>(txt).unwrap();
+        assert_eq!(res.delimiter, "/");
+        assert_eq!(res.prefixes(), vec!["a/", "b/"]);
+        assert_eq!(res.contents.len(), 1);
+        assert_eq!(res.contents[0].key, "c");
+    }
+
+    #[test]
+    fn test_list_bucket_directories() {
+        let txt = r#"<ListBucketResult><Name>bucket</Name><Prefix>photos/</Prefix><KeyCount>3</KeyCount><MaxKeys>1000</MaxKeys><Delimiter>/</Delimiter><IsTruncated>false</IsTruncated>
+            <Contents><Key>photos/</Key><LastModified>2023-09-10T08:16:28.230Z</LastModified><ETag>"d41d8cd98f00b204e9800998ecf8427e"</ETag><Size>0</Size><StorageClass>STANDARD</StorageClass></Contents>
+            <Contents><Key>photos/2024/</Key><LastModified>2023-09-10T08:16:28.230Z</LastModified><ETag>"d41d8cd98f00b204e9800998ecf8427e"</ETag><Size>0</Size><StorageClass>STANDARD</StorageClass></Contents>
+            <Contents><Key>photos/a.jpg</Key><LastModified>2023-09-10T08:16:28.230Z</LastModified><ETag>"5eb63bbbe01eeed093cb22bb8f5acdc3"</ETag><Size>11</Size><StorageClass>STANDARD</StorageClass></Contents>
+            <CommonPrefixes><Prefix>photos/2023/</Prefix></CommonPrefixes><CommonPrefixes><Prefix>photos/2024/</Prefix></CommonPrefixes>
+            </ListBucketResult>"#;
+        let res = crate::xml::de::from_str::<ListBucketResult>(txt).unwrap();
+        assert_eq!(res.directories(), vec!["photos/2023/", "photos/2024/"]);
+        assert!(res.contents[1].is_dir());
+        assert!(!res.contents[2].is_dir());
+    }
+
     #[test]
     fn test_struct() {
         #[derive(Deserialize, PartialEq, Debug)]
@@ -28,9 +28,6 @@ pub fn get_test_minio() -> Minio {
 }
 
 pub async fn create_bucket_if_not_exist(minio: &Minio, bucket: &str) -> Result<()> {
-    let exists = minio.bucket_exists(bucket).await?;
-    if !exists {
-        minio.make_bucket(bucket, false).await?;
-    }
+    minio.make_bucket_if_not_exists(bucket, false).await?;
     return Ok(());
 }
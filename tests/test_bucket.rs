@@ -59,8 +59,13 @@ async fn test_bucket() -> Result<()> {
 
     println!("====== begin clear test bucket");
     assert!(minio.bucket_exists(bucket1).await?);
+    assert_eq!(
+        minio.head_bucket(bucket1).await?.as_deref(),
+        Some(minio.region())
+    );
     assert!(minio.remove_bucket(bucket1).await.is_ok());
     assert!(!minio.bucket_exists(bucket1).await?);
+    assert!(minio.head_bucket(bucket1).await?.is_none());
     assert!(minio.remove_bucket(bucket2).await.is_ok());
 
     Ok(())
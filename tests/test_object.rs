@@ -3,10 +3,12 @@ mod common;
 use std::collections::HashMap;
 use std::str::FromStr;
 
+use bytes::Bytes;
 use common::{create_bucket_if_not_exist, get_test_minio};
 use futures_util::{stream, StreamExt};
 use sc_minio::client::CopySource;
 use sc_minio::client::KeyArgs;
+use sc_minio::client::ListObjectsArgs;
 use sc_minio::client::ObjectLockConfig;
 use sc_minio::client::Tags;
 use sc_minio::datatype::CompressionType;
@@ -15,7 +17,8 @@ use sc_minio::datatype::InputSerialization;
 use sc_minio::datatype::JsonOutput;
 use sc_minio::datatype::ObjectLockConfiguration;
 use sc_minio::datatype::SelectRequest;
-use sc_minio::error::Result;
+use sc_minio::datatype::VersioningStatus;
+use sc_minio::error::{Error, Result};
 use tokio;
 
 #[tokio::main]
@@ -60,6 +63,133 @@ async fn test_base_operate() -> Result<()> {
     Ok(())
 }
 
+#[tokio::main]
+#[test]
+async fn test_empty_object() -> Result<()> {
+    let minio = get_test_minio();
+    let bucket_name = "test-object-empty";
+    let bucket = minio.bucket(bucket_name);
+    create_bucket_if_not_exist(&minio, bucket_name).await?;
+
+    bucket.put_object("empty.txt", Bytes::new()).await?;
+    let stat = bucket.stat_object("empty.txt").await?.unwrap();
+    assert_eq!(stat.size(), 0);
+    let res = bucket.get_object("empty.txt").await?;
+    assert!(res.bytes().await?.is_empty());
+
+    bucket.remove_object("empty.txt").await?;
+    minio.remove_bucket(bucket_name).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_object_metadata() -> Result<()> {
+    let minio = get_test_minio();
+    let bucket_name = "test-object-metadata";
+    let bucket = minio.bucket(bucket_name);
+    create_bucket_if_not_exist(&minio, bucket_name).await?;
+
+    let metadata: HashMap<String, String> = [
+        ("Filename".to_owned(), "file.txt".to_owned()),
+        ("owner".to_owned(), "minio".to_owned()),
+        ("x-amz-meta-size".to_owned(), "11".to_owned()),
+    ]
+    .into();
+    let key = KeyArgs::new("meta.txt").metadata(metadata);
+    bucket.put_object(key, "hello minio".into()).await?;
+    let stat = bucket.stat_object("meta.txt").await?.unwrap();
+    assert_eq!(stat.metadata()["filename"], "file.txt");
+    assert_eq!(stat.metadata()["owner"], "minio");
+    assert_eq!(stat.metadata()["size"], "11");
+
+    bucket.remove_object("meta.txt").await?;
+    minio.remove_bucket(bucket_name).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_put_object_if_not_exists() -> Result<()> {
+    let minio = get_test_minio();
+    let bucket_name = "test-object-if-not-exists";
+    let bucket = minio.bucket(bucket_name);
+    create_bucket_if_not_exist(&minio, bucket_name).await?;
+
+    bucket.put_object_if_not_exists("lock", "a".into()).await?;
+    match bucket.put_object_if_not_exists("lock", "b".into()).await {
+        Err(Error::AlreadyExists(_)) => {}
+        _ => panic!("expected AlreadyExists"),
+    }
+    let res = bucket.get_object("lock").await?;
+    assert_eq!(res.text().await?, "a");
+
+    bucket.remove_object("lock").await?;
+    minio.remove_bucket(bucket_name).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_list_common_prefixes() -> Result<()> {
+    let minio = get_test_minio();
+    let bucket_name = "test-object-common-prefixes";
+    let bucket = minio.bucket(bucket_name);
+    create_bucket_if_not_exist(&minio, bucket_name).await?;
+
+    for key in ["a/1", "a/2", "b/1"] {
+        bucket.put_object(key, "hello".into()).await?;
+    }
+    let args = ListObjectsArgs::default().delimiter("/");
+    let res = bucket.list_objects(args).await?;
+    assert_eq!(res.prefixes(), vec!["a/", "b/"]);
+    assert!(res.contents.is_empty());
+
+    for key in ["a/1", "a/2", "b/1"] {
+        bucket.remove_object(key).await?;
+    }
+    minio.remove_bucket(bucket_name).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_copy_object_version() -> Result<()> {
+    let minio = get_test_minio();
+    let bucket_name = "test-object-copy-version";
+    let bucket = minio.bucket(bucket_name);
+    create_bucket_if_not_exist(&minio, bucket_name).await?;
+    let mut versioning = minio.get_bucket_versioning(bucket_name).await?;
+    versioning.status = Some(VersioningStatus::Enabled);
+    minio.set_bucket_versioning(bucket_name, versioning).await?;
+
+    let v1 = bucket
+        .upload("source.txt", Bytes::from("version 1").into())
+        .await?;
+    let v2 = bucket
+        .upload("source.txt", Bytes::from("version 2").into())
+        .await?;
+    let v1_id = v1.version_id().unwrap().to_owned();
+
+    let copy = CopySource::new(bucket_name, "source.txt").version_id(v1_id.clone());
+    bucket.copy_object("copied.txt", copy).await?;
+    let copied = bucket.stat_object("copied.txt").await?.unwrap();
+    let res = bucket.get_object("copied.txt").await?;
+    assert_eq!(res.text().await?, "version 1");
+
+    for (key, version_id) in [
+        ("source.txt", Some(v1_id)),
+        ("source.txt", v2.version_id().map(String::from)),
+        ("copied.txt", Some(copied.version_id().to_owned())),
+    ] {
+        bucket
+            .remove_object(KeyArgs::new(key).version_id(version_id))
+            .await?;
+    }
+    minio.remove_bucket(bucket_name).await?;
+    Ok(())
+}
+
 // #[tokio::main]
 // #[test]
 // async fn test_retention() -> Result<()> {
@@ -106,6 +236,14 @@ async fn test_file_operate() -> Result<()> {
     bucket
         .fput_object("lena_std.jpeg", "tests/lena_std.jpeg")
         .await?;
+    bucket
+        .fget_object_concurrent("lena_std.jpeg", "tests/lena_std.download.jpeg", 4, 8 * 1024)
+        .await?;
+    assert_eq!(
+        std::fs::read("tests/lena_std.jpeg")?,
+        std::fs::read("tests/lena_std.download.jpeg")?
+    );
+    std::fs::remove_file("tests/lena_std.download.jpeg")?;
     bucket.remove_object("lena_std.jpeg").await?;
 
     bucket.stat_object(key.clone()).await?;